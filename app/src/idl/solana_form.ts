@@ -1,236 +1,5937 @@
 // app/src/idl/solana_form.ts
 // Generated type definitions for the Solana Form program
+// camelCase copy of solana_form.json, regenerate both from the program IDL
 
 export type SolanaForm = {
-  version: "0.1.0";
-  name: "solana_form";
-  instructions: [
-    {
-      name: "initializeForm";
-      accounts: [
-        { name: "form"; isMut: true; isSigner: false },
-        { name: "authority"; isMut: true; isSigner: true },
-        { name: "systemProgram"; isMut: false; isSigner: false }
-      ];
-      args: [
-        { name: "formId"; type: "string" },
-        { name: "prizePool"; type: "u64" },
-        { name: "deadline"; type: "i64" },
-        { name: "maxParticipants"; type: "u32" }
-      ];
-    },
-    {
-      name: "depositPrize";
-      accounts: [
-        { name: "form"; isMut: true; isSigner: false },
-        { name: "authority"; isMut: true; isSigner: true },
-        { name: "systemProgram"; isMut: false; isSigner: false }
-      ];
-      args: [];
-    },
-    {
-      name: "submitForm";
-      accounts: [
-        { name: "form"; isMut: true; isSigner: false },
-        { name: "participant"; isMut: true; isSigner: false },
-        { name: "user"; isMut: true; isSigner: true },
-        { name: "systemProgram"; isMut: false; isSigner: false }
-      ];
-      args: [{ name: "emailHash"; type: { array: ["u8", 32] } }];
-    },
-    {
-      name: "distributePrizes";
-      accounts: [
-        { name: "form"; isMut: true; isSigner: false },
-        { name: "authority"; isMut: true; isSigner: true }
-      ];
-      args: [];
-    },
-    {
-      name: "checkAndClaimPrize";
-      accounts: [
-        { name: "form"; isMut: true; isSigner: false },
-        { name: "participant"; isMut: true; isSigner: false },
-        { name: "winner"; isMut: true; isSigner: false },
-        { name: "systemProgram"; isMut: false; isSigner: false }
-      ];
-      args: [];
-    },
-    {
-      name: "closeForm";
-      accounts: [
-        { name: "form"; isMut: true; isSigner: false },
-        { name: "authority"; isMut: true; isSigner: true }
-      ];
-      args: [];
+  "address": "FnBCbFZ1Y4rhjRuKe94HDfXcxkjL9DiU21YhCJzHRcZY",
+  "metadata": {
+    "name": "solanaForm",
+    "version": "0.1.0",
+    "spec": "0.1.0",
+    "description": "Created with Anchor"
+  },
+  "instructions": [
+    {
+      "name": "acceptAuthority",
+      "docs": [
+        "Pending authority accepts the transfer and becomes the form's authority"
+      ],
+      "discriminator": [
+        107,
+        86,
+        198,
+        91,
+        33,
+        12,
+        107,
+        160
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "newAuthority",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelForm",
+      "docs": [
+        "Cancel the form before randomness is requested (or a quiz's answer key is revealed)",
+        "and refund the pool to the authority"
+      ],
+      "discriminator": [
+        205,
+        5,
+        1,
+        63,
+        199,
+        181,
+        241,
+        84
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "form"
+          ]
+        },
+        {
+          "name": "prizeMint",
+          "optional": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authorityTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "checkWinnerStatus",
+      "docs": [
+        "Winner lookup - ANYONE can call this, claim_prize does not depend on it"
+      ],
+      "discriminator": [
+        220,
+        112,
+        26,
+        176,
+        127,
+        203,
+        126,
+        41
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          },
+          "relations": [
+            "participant"
+          ]
+        },
+        {
+          "name": "participant",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  114,
+                  116,
+                  105,
+                  99,
+                  105,
+                  112,
+                  97,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              },
+              {
+                "kind": "account",
+                "path": "participant.wallet",
+                "account": "participant"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimPrize",
+      "docs": [
+        "Winner claims their prize, minus the protocol fee snapshotted when the form was created",
+        "Token forms with a fee need a `treasury_token_account` of the prize mint"
+      ],
+      "discriminator": [
+        157,
+        233,
+        139,
+        121,
+        246,
+        62,
+        234,
+        235
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          },
+          "relations": [
+            "participant"
+          ]
+        },
+        {
+          "name": "participant",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  114,
+                  116,
+                  105,
+                  99,
+                  105,
+                  112,
+                  97,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              },
+              {
+                "kind": "account",
+                "path": "winner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "winner",
+          "writable": true
+        },
+        {
+          "name": "prizeMint",
+          "optional": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "winnerTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeEmailClaim",
+      "docs": [
+        "Close an email claim once submissions are closed, rent goes back to the wallet"
+      ],
+      "discriminator": [
+        240,
+        114,
+        167,
+        216,
+        240,
+        87,
+        39,
+        172
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          },
+          "relations": [
+            "email_claim"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "emailClaim",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  109,
+                  97,
+                  105,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              },
+              {
+                "kind": "arg",
+                "path": "emailHash"
+              }
+            ]
+          }
+        },
+        {
+          "name": "wallet",
+          "writable": true,
+          "relations": [
+            "email_claim"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "emailHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeForm",
+      "docs": [
+        "Close form and refund remaining funds to authority"
+      ],
+      "discriminator": [
+        58,
+        128,
+        2,
+        243,
+        149,
+        169,
+        144,
+        144
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "form"
+          ]
+        },
+        {
+          "name": "formSchema",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  99,
+                  104,
+                  101,
+                  109,
+                  97
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "leaderboard",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "prizeMint",
+          "optional": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authorityTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeParticipant",
+      "docs": [
+        "Close a participant of a cancelled form, entry fee and rent go back to the participant",
+        "Pass the participant's `response`, if any, to close it too"
+      ],
+      "discriminator": [
+        192,
+        162,
+        92,
+        5,
+        148,
+        191,
+        207,
+        151
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          },
+          "relations": [
+            "participant",
+            "response"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "participant",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  114,
+                  116,
+                  105,
+                  99,
+                  105,
+                  112,
+                  97,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              },
+              {
+                "kind": "account",
+                "path": "wallet"
+              }
+            ]
+          }
+        },
+        {
+          "name": "response",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  115,
+                  112,
+                  111,
+                  110,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              },
+              {
+                "kind": "account",
+                "path": "wallet"
+              }
+            ]
+          }
+        },
+        {
+          "name": "wallet",
+          "writable": true,
+          "relations": [
+            "participant",
+            "response"
+          ]
+        },
+        {
+          "name": "prizeMint",
+          "optional": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "walletTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeResponse",
+      "docs": [
+        "Close a response once the form is settled or cancelled, rent goes back to the wallet"
+      ],
+      "discriminator": [
+        208,
+        219,
+        27,
+        58,
+        136,
+        18,
+        249,
+        57
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          },
+          "relations": [
+            "response"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "response",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  115,
+                  112,
+                  111,
+                  110,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              },
+              {
+                "kind": "account",
+                "path": "wallet"
+              }
+            ]
+          }
+        },
+        {
+          "name": "wallet",
+          "writable": true,
+          "relations": [
+            "response"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createFormSchema",
+      "docs": [
+        "Create the question schema answers are validated against, before submissions open",
+        "Pass `response_encryption_key` to only accept responses encrypted to that X25519 key",
+        "Operators can create the schema, only the authority can set the encryption key"
+      ],
+      "discriminator": [
+        144,
+        183,
+        28,
+        60,
+        194,
+        117,
+        22,
+        159
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "formSchema",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  99,
+                  104,
+                  101,
+                  109,
+                  97
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "docs": [
+            "Form authority, or an operator allowed to edit the form metadata"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "questions",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "question"
+              }
+            }
+          }
+        },
+        {
+          "name": "maxResponseSize",
+          "type": "u32"
+        },
+        {
+          "name": "responseEncryptionKey",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "declareWinner",
+      "docs": [
+        "Admin-declared backend: authority declares each winner"
+      ],
+      "discriminator": [
+        140,
+        135,
+        197,
+        50,
+        9,
+        23,
+        4,
+        80
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          },
+          "relations": [
+            "participant"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "participant",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  114,
+                  116,
+                  105,
+                  99,
+                  105,
+                  112,
+                  97,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              },
+              {
+                "kind": "account",
+                "path": "participant.wallet",
+                "account": "participant"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "form"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "depositPrize",
+      "docs": [
+        "Deposit prize pool by form creator"
+      ],
+      "discriminator": [
+        245,
+        164,
+        83,
+        19,
+        96,
+        75,
+        73,
+        130
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "form"
+          ]
+        },
+        {
+          "name": "prizeMint",
+          "optional": true
+        },
+        {
+          "name": "authorityTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "distributePrizes",
+      "docs": [
+        "Slot-hash backend: derive the seed from the latest slot hash"
+      ],
+      "discriminator": [
+        154,
+        99,
+        201,
+        93,
+        82,
+        104,
+        73,
+        232
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          },
+          "relations": [
+            "leaderboard"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "docs": [
+            "Form authority, or an operator allowed to request randomness"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "slotHashes",
+          "address": "SysvarS1otHashes111111111111111111111111111"
+        },
+        {
+          "name": "leaderboard",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "emergencyFallback",
+      "docs": [
+        "Switchboard backend, step 2B: emergency fallback if the oracle fails"
+      ],
+      "discriminator": [
+        231,
+        136,
+        220,
+        83,
+        5,
+        180,
+        24,
+        223
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          },
+          "relations": [
+            "leaderboard"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "form"
+          ]
+        },
+        {
+          "name": "slotHashes",
+          "address": "SysvarS1otHashes111111111111111111111111111"
+        },
+        {
+          "name": "leaderboard",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "extendDeadline",
+      "docs": [
+        "Authority or an operator pushes the deadline back while submissions are open"
+      ],
+      "discriminator": [
+        18,
+        209,
+        27,
+        175,
+        87,
+        13,
+        213,
+        188
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "docs": [
+            "Form authority, or an operator allowed to extend the deadline"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newDeadline",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "forfeitReveal",
+      "docs": [
+        "Commit-reveal backend, step 2B: authority never revealed, pool is forfeited"
+      ],
+      "discriminator": [
+        253,
+        7,
+        6,
+        55,
+        52,
+        91,
+        213,
+        29
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "fundRentReserve",
+      "docs": [
+        "Creator funds the reserve that reimburses relayers for gasless submissions"
+      ],
+      "discriminator": [
+        11,
+        176,
+        73,
+        108,
+        1,
+        124,
+        100,
+        101
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "form"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initializeConfig",
+      "docs": [
+        "Create the program config, signed by the program's upgrade authority"
+      ],
+      "discriminator": [
+        208,
+        127,
+        21,
+        1,
+        194,
+        190,
+        196,
+        70
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "address": "FnBCbFZ1Y4rhjRuKe94HDfXcxkjL9DiU21YhCJzHRcZY"
+        },
+        {
+          "name": "programData"
+        },
+        {
+          "name": "upgradeAuthority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "admin",
+          "type": "pubkey"
+        },
+        {
+          "name": "protocolFeeBps",
+          "type": "u16"
+        },
+        {
+          "name": "treasury",
+          "type": "pubkey"
+        },
+        {
+          "name": "maxPrizePool",
+          "type": "u64"
+        },
+        {
+          "name": "maxWinners",
+          "type": "u32"
+        },
+        {
+          "name": "maxDuration",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "initializeForm",
+      "docs": [
+        "Initialize a new form with prize pool",
+        "Pass `seed_commitment = hash(secret)` for the commit-reveal backend",
+        "Pass `prize_mint`, `vault` and `token_program` for an SPL Token / Token-2022 pool",
+        "`entry_fee` is charged in the same currency, `creator_fee_bps` of it goes to the authority",
+        "Pass `allowlist_root` to restrict submissions to the wallets in a Merkle allowlist",
+        "Pass `email_verifier` to only accept emails attested by that key",
+        "`questions_hash` commits to the question set, `schema_version` identifies it",
+        "Pass `answer_key_commitment` for a quiz, only correct answers can then win",
+        "Skill-ranked quizzes pay the prize schedule tiers by score instead of drawing"
+      ],
+      "discriminator": [
+        173,
+        39,
+        56,
+        137,
+        179,
+        121,
+        137,
+        253
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "formId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "prizeMint",
+          "docs": [
+            "Optional: SPL Token or Token-2022 mint the prize is paid in (native SOL if omitted)"
+          ],
+          "optional": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "formId",
+          "type": "string"
+        },
+        {
+          "name": "prizePool",
+          "type": "u64"
+        },
+        {
+          "name": "deadline",
+          "type": "i64"
+        },
+        {
+          "name": "maxParticipants",
+          "type": "u32"
+        },
+        {
+          "name": "winnerCount",
+          "type": "u32"
+        },
+        {
+          "name": "randomnessBackend",
+          "type": {
+            "defined": {
+              "name": "randomnessBackend"
+            }
+          }
+        },
+        {
+          "name": "seedCommitment",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        },
+        {
+          "name": "prizeSchedule",
+          "type": {
+            "defined": {
+              "name": "prizeSchedule"
+            }
+          }
+        },
+        {
+          "name": "allowOverfunding",
+          "type": "bool"
+        },
+        {
+          "name": "entryFee",
+          "type": "u64"
+        },
+        {
+          "name": "creatorFeeBps",
+          "type": "u16"
+        },
+        {
+          "name": "allowlistRoot",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        },
+        {
+          "name": "emailVerifier",
+          "type": {
+            "option": "pubkey"
+          }
+        },
+        {
+          "name": "questionsHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "schemaVersion",
+          "type": "u16"
+        },
+        {
+          "name": "answerKeyCommitment",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        },
+        {
+          "name": "winnerSelection",
+          "type": {
+            "defined": {
+              "name": "winnerSelection"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "markEligible",
+      "docs": [
+        "Quiz: prove a participant's committed answers match the key - ANYONE can call this"
+      ],
+      "discriminator": [
+        53,
+        39,
+        116,
+        128,
+        184,
+        136,
+        247,
+        40
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          },
+          "relations": [
+            "participant"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "participant",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  114,
+                  116,
+                  105,
+                  99,
+                  105,
+                  112,
+                  97,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              },
+              {
+                "kind": "account",
+                "path": "participant.wallet",
+                "account": "participant"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "answers",
+          "type": "bytes"
+        },
+        {
+          "name": "salt",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "proposeAuthority",
+      "docs": [
+        "Authority proposes a new authority, which takes over once it accepts"
+      ],
+      "discriminator": [
+        20,
+        148,
+        236,
+        198,
+        76,
+        119,
+        99,
+        142
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "form"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "newAuthority",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "recordScore",
+      "docs": [
+        "Skill-ranked: score a participant's committed answers - ANYONE can call this"
+      ],
+      "discriminator": [
+        30,
+        181,
+        94,
+        137,
+        7,
+        160,
+        236,
+        158
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          },
+          "relations": [
+            "participant",
+            "leaderboard"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "participant",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  114,
+                  116,
+                  105,
+                  99,
+                  105,
+                  112,
+                  97,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              },
+              {
+                "kind": "account",
+                "path": "participant.wallet",
+                "account": "participant"
+              }
+            ]
+          }
+        },
+        {
+          "name": "leaderboard",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "answers",
+          "type": "bytes"
+        },
+        {
+          "name": "salt",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "refundSponsor",
+      "docs": [
+        "Refund a sponsor of a cancelled form and close their sponsorship"
+      ],
+      "discriminator": [
+        137,
+        0,
+        158,
+        28,
+        172,
+        220,
+        165,
+        25
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          },
+          "relations": [
+            "sponsorship"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "sponsorship",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  112,
+                  111,
+                  110,
+                  115,
+                  111,
+                  114,
+                  115,
+                  104,
+                  105,
+                  112
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              },
+              {
+                "kind": "account",
+                "path": "sponsor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sponsor",
+          "writable": true,
+          "relations": [
+            "sponsorship"
+          ]
+        },
+        {
+          "name": "prizeMint",
+          "optional": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sponsorTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "requestRandomness",
+      "docs": [
+        "Switchboard backend, step 1: authority or an operator requests randomness",
+        "Commit the Switchboard randomness account in the previous slot, older accounts are rejected"
+      ],
+      "discriminator": [
+        213,
+        5,
+        173,
+        166,
+        37,
+        236,
+        31,
+        18
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "docs": [
+            "Form authority, or an operator allowed to request randomness"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "randomnessAccount"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "requestReveal",
+      "docs": [
+        "Commit-reveal backend, step 1: lock the future slot mixed into the seed"
+      ],
+      "discriminator": [
+        68,
+        34,
+        124,
+        109,
+        202,
+        72,
+        142,
+        90
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "revealAnswerKey",
+      "docs": [
+        "Quiz: authority reveals the committed answer key after the deadline",
+        "Pass the `leaderboard` account for skill-ranked forms to create it"
+      ],
+      "discriminator": [
+        77,
+        161,
+        135,
+        150,
+        111,
+        20,
+        104,
+        241
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "form"
+          ]
+        },
+        {
+          "name": "leaderboard",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "answerKey",
+          "type": "bytes"
+        },
+        {
+          "name": "salt",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "revealSeed",
+      "docs": [
+        "Commit-reveal backend, step 2: authority reveals the committed secret"
+      ],
+      "discriminator": [
+        196,
+        119,
+        194,
+        112,
+        156,
+        211,
+        239,
+        105
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          },
+          "relations": [
+            "leaderboard"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "form"
+          ]
+        },
+        {
+          "name": "slotHashes",
+          "address": "SysvarS1otHashes111111111111111111111111111"
+        },
+        {
+          "name": "leaderboard",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "secret",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "setAllowlist",
+      "docs": [
+        "Authority sets or clears the Merkle allowlist before submissions close"
+      ],
+      "discriminator": [
+        141,
+        30,
+        41,
+        131,
+        132,
+        7,
+        216,
+        134
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "docs": [
+            "Form authority, or an operator allowed to edit the form metadata"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "allowlistRoot",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setOperator",
+      "docs": [
+        "Authority grants an operator PERMISSION_* bits, 0 removes the operator",
+        "Operators may request randomness, extend the deadline or edit the form metadata"
+      ],
+      "discriminator": [
+        238,
+        153,
+        101,
+        169,
+        243,
+        131,
+        36,
+        1
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "form"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "operator",
+          "type": "pubkey"
+        },
+        {
+          "name": "permissions",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "setParticipationGate",
+      "docs": [
+        "Authority restricts submissions to token or NFT holders before submissions close"
+      ],
+      "discriminator": [
+        100,
+        45,
+        162,
+        30,
+        77,
+        180,
+        183,
+        143
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "docs": [
+            "Form authority, or an operator allowed to edit the form metadata"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "gate",
+          "type": {
+            "defined": {
+              "name": "participationGate"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPaused",
+      "docs": [
+        "Admin pauses or resumes every form instruction, winner checks and prize claims stay open"
+      ],
+      "discriminator": [
+        91,
+        60,
+        125,
+        192,
+        176,
+        225,
+        166,
+        218
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "settleRandomness",
+      "docs": [
+        "Switchboard backend, step 2: settle randomness from the oracle"
+      ],
+      "discriminator": [
+        209,
+        111,
+        84,
+        239,
+        14,
+        4,
+        26,
+        251
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          },
+          "relations": [
+            "leaderboard"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "docs": [
+            "Form authority, or an operator allowed to request randomness"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "randomnessAccount"
+        },
+        {
+          "name": "leaderboard",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "sponsorDeposit",
+      "docs": [
+        "Anyone can top up the prize pool, tracked per sponsor"
+      ],
+      "discriminator": [
+        206,
+        47,
+        139,
+        60,
+        184,
+        252,
+        38,
+        155
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "sponsorship",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  112,
+                  111,
+                  110,
+                  115,
+                  111,
+                  114,
+                  115,
+                  104,
+                  105,
+                  112
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              },
+              {
+                "kind": "account",
+                "path": "sponsor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sponsor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "prizeMint",
+          "optional": true
+        },
+        {
+          "name": "sponsorTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "submitForm",
+      "docs": [
+        "Submit form and register participant",
+        "A `payer` other than `user` (a relayer) is reimbursed the rent from the form's reserve",
+        "Gated forms need the user's `gate_token_account`, and `gate_metadata` for collections",
+        "Allowlisted forms need the wallet's Merkle proof and allocation (0 if unused)",
+        "Email-verified forms need the verifier's Ed25519 instruction right before this one",
+        "`responses_hash` commits to the answers given for the form's `schema_version`"
+      ],
+      "discriminator": [
+        251,
+        129,
+        69,
+        66,
+        111,
+        39,
+        186,
+        171
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "participant",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  114,
+                  116,
+                  105,
+                  99,
+                  105,
+                  112,
+                  97,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "emailClaim",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  109,
+                  97,
+                  105,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              },
+              {
+                "kind": "arg",
+                "path": "emailHash"
+              }
+            ]
+          }
+        },
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "prizeMint",
+          "optional": true
+        },
+        {
+          "name": "userTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "gateTokenAccount",
+          "optional": true
+        },
+        {
+          "name": "gateMetadata",
+          "optional": true
+        },
+        {
+          "name": "instructionsSysvar",
+          "optional": true,
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "emailHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "allowlistProof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        },
+        {
+          "name": "allocation",
+          "type": "u64"
+        },
+        {
+          "name": "attestationExpiry",
+          "type": "i64"
+        },
+        {
+          "name": "responsesHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "schemaVersion",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "submitResponse",
+      "docs": [
+        "Store a participant's answers on-chain, validated against the form schema"
+      ],
+      "discriminator": [
+        85,
+        190,
+        208,
+        119,
+        243,
+        52,
+        133,
+        90
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          },
+          "relations": [
+            "form_schema",
+            "participant"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "formSchema",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  99,
+                  104,
+                  101,
+                  109,
+                  97
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "participant",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  114,
+                  116,
+                  105,
+                  99,
+                  105,
+                  112,
+                  97,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              },
+              {
+                "kind": "account",
+                "path": "wallet"
+              }
+            ]
+          }
+        },
+        {
+          "name": "response",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  115,
+                  112,
+                  111,
+                  110,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              },
+              {
+                "kind": "account",
+                "path": "wallet"
+              }
+            ]
+          }
+        },
+        {
+          "name": "wallet",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "participant"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "payload",
+          "type": {
+            "defined": {
+              "name": "responsePayload"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "sweepUnclaimed",
+      "docs": [
+        "Return unclaimed prizes to the authority once the claim window has ended"
+      ],
+      "discriminator": [
+        64,
+        168,
+        221,
+        224,
+        42,
+        216,
+        138,
+        144
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "form"
+          ]
+        },
+        {
+          "name": "prizeMint",
+          "optional": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authorityTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateConfig",
+      "docs": [
+        "Admin updates the config, `admin` can hand the role over"
+      ],
+      "discriminator": [
+        29,
+        158,
+        252,
+        191,
+        10,
+        83,
+        219,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "admin",
+          "type": "pubkey"
+        },
+        {
+          "name": "protocolFeeBps",
+          "type": "u16"
+        },
+        {
+          "name": "treasury",
+          "type": "pubkey"
+        },
+        {
+          "name": "maxPrizePool",
+          "type": "u64"
+        },
+        {
+          "name": "maxWinners",
+          "type": "u32"
+        },
+        {
+          "name": "maxDuration",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "updateResponse",
+      "docs": [
+        "Replace a participant's answers before the deadline, resizing the account"
+      ],
+      "discriminator": [
+        219,
+        130,
+        186,
+        111,
+        106,
+        71,
+        33,
+        108
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          },
+          "relations": [
+            "form_schema",
+            "response"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "formSchema",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  99,
+                  104,
+                  101,
+                  109,
+                  97
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "response",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  115,
+                  112,
+                  111,
+                  110,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              },
+              {
+                "kind": "account",
+                "path": "wallet"
+              }
+            ]
+          }
+        },
+        {
+          "name": "wallet",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "response"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "payload",
+          "type": {
+            "defined": {
+              "name": "responsePayload"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "withdrawCreatorFees",
+      "docs": [
+        "Authority withdraws its cut of the entry fees once the draw has started"
+      ],
+      "discriminator": [
+        8,
+        30,
+        213,
+        18,
+        121,
+        105,
+        129,
+        222
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "form"
+          ]
+        },
+        {
+          "name": "prizeMint",
+          "optional": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authorityTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawSubmission",
+      "docs": [
+        "Leave a form before the deadline, entry fee and rent go back to the participant",
+        "Pass the participant holding the last index as `last_participant`, it takes the freed one",
+        "When the last participant leaves the form goes back to Funded"
+      ],
+      "discriminator": [
+        242,
+        62,
+        195,
+        3,
+        192,
+        241,
+        52,
+        174
+      ],
+      "accounts": [
+        {
+          "name": "form",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form.form_id",
+                "account": "form"
+              }
+            ]
+          },
+          "relations": [
+            "participant",
+            "email_claim",
+            "response",
+            "last_participant"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "participant",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  114,
+                  116,
+                  105,
+                  99,
+                  105,
+                  112,
+                  97,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              },
+              {
+                "kind": "account",
+                "path": "wallet"
+              }
+            ]
+          }
+        },
+        {
+          "name": "emailClaim",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  109,
+                  97,
+                  105,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              },
+              {
+                "kind": "account",
+                "path": "participant.email_hash",
+                "account": "participant"
+              }
+            ]
+          }
+        },
+        {
+          "name": "response",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  115,
+                  112,
+                  111,
+                  110,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              },
+              {
+                "kind": "account",
+                "path": "wallet"
+              }
+            ]
+          }
+        },
+        {
+          "name": "lastParticipant",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  114,
+                  116,
+                  105,
+                  99,
+                  105,
+                  112,
+                  97,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              },
+              {
+                "kind": "account",
+                "path": "last_participant.wallet",
+                "account": "participant"
+              }
+            ]
+          }
+        },
+        {
+          "name": "wallet",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "participant",
+            "email_claim",
+            "response"
+          ]
+        },
+        {
+          "name": "prizeMint",
+          "optional": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "form"
+              }
+            ]
+          }
+        },
+        {
+          "name": "walletTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawTreasury",
+      "docs": [
+        "Admin withdraws protocol fees from the treasury to the config's treasury wallet"
+      ],
+      "discriminator": [
+        40,
+        63,
+        122,
+        158,
+        144,
+        216,
+        83,
+        96
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "recipient",
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "optional": true
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "recipientTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
     }
-  ];
-  accounts: [
-    {
-      name: "form";
-      type: {
-        kind: "struct";
-        fields: [
-          { name: "authority"; type: "publicKey" },
-          { name: "formId"; type: "string" },
-          { name: "prizePool"; type: "u64" },
-          { name: "collectedAmount"; type: "u64" },
-          { name: "deadline"; type: "i64" },
-          { name: "maxParticipants"; type: "u32" },
-          { name: "participantCount"; type: "u32" },
-          { name: "isActive"; type: "bool" },
-          { name: "isDistributed"; type: "bool" },
-          { name: "bump"; type: "u8" },
-          { name: "randomSeed"; type: "u64" }
-        ];
-      };
-    },
-    {
-      name: "participant";
-      type: {
-        kind: "struct";
-        fields: [
-          { name: "wallet"; type: "publicKey" },
-          { name: "form"; type: "publicKey" },
-          { name: "emailHash"; type: { array: ["u8", 32] } },
-          { name: "timestamp"; type: "i64" },
-          { name: "participantIndex"; type: "u32" },
-          { name: "isWinner"; type: "bool" },
-          { name: "claimed"; type: "bool" },
-          { name: "bump"; type: "u8" }
-        ];
-      };
+  ],
+  "accounts": [
+    {
+      "name": "config",
+      "discriminator": [
+        155,
+        12,
+        170,
+        224,
+        30,
+        250,
+        204,
+        130
+      ]
+    },
+    {
+      "name": "emailClaim",
+      "discriminator": [
+        88,
+        255,
+        181,
+        53,
+        253,
+        147,
+        134,
+        160
+      ]
+    },
+    {
+      "name": "form",
+      "discriminator": [
+        38,
+        144,
+        5,
+        62,
+        130,
+        234,
+        18,
+        216
+      ]
+    },
+    {
+      "name": "formSchema",
+      "discriminator": [
+        191,
+        47,
+        123,
+        97,
+        25,
+        38,
+        81,
+        52
+      ]
+    },
+    {
+      "name": "leaderboard",
+      "discriminator": [
+        247,
+        186,
+        238,
+        243,
+        194,
+        30,
+        9,
+        36
+      ]
+    },
+    {
+      "name": "participant",
+      "discriminator": [
+        32,
+        142,
+        108,
+        79,
+        247,
+        179,
+        54,
+        6
+      ]
+    },
+    {
+      "name": "response",
+      "discriminator": [
+        198,
+        155,
+        246,
+        149,
+        75,
+        240,
+        81,
+        122
+      ]
+    },
+    {
+      "name": "sponsorship",
+      "discriminator": [
+        191,
+        110,
+        48,
+        7,
+        9,
+        44,
+        168,
+        248
+      ]
+    },
+    {
+      "name": "treasury",
+      "discriminator": [
+        238,
+        239,
+        123,
+        238,
+        89,
+        1,
+        168,
+        253
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "formStatusChanged",
+      "discriminator": [
+        38,
+        123,
+        210,
+        167,
+        241,
+        121,
+        98,
+        214
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "formInactive",
+      "msg": "Form is not active"
+    },
+    {
+      "code": 6001,
+      "name": "alreadyDistributed",
+      "msg": "Prizes already distributed"
+    },
+    {
+      "code": 6002,
+      "name": "prizePoolFilled",
+      "msg": "Prize pool already filled"
+    },
+    {
+      "code": 6003,
+      "name": "deadlinePassed",
+      "msg": "Deadline has passed"
+    },
+    {
+      "code": 6004,
+      "name": "deadlineNotReached",
+      "msg": "Deadline has not been reached yet"
+    },
+    {
+      "code": 6005,
+      "name": "maxParticipantsReached",
+      "msg": "Maximum participants reached"
+    },
+    {
+      "code": 6006,
+      "name": "unauthorized",
+      "msg": "unauthorized"
+    },
+    {
+      "code": 6007,
+      "name": "noParticipants",
+      "msg": "No participants to distribute to"
+    },
+    {
+      "code": 6008,
+      "name": "notDistributed",
+      "msg": "Prizes not distributed yet"
+    },
+    {
+      "code": 6009,
+      "name": "alreadyClaimed",
+      "msg": "Prize already claimed"
+    },
+    {
+      "code": 6010,
+      "name": "cannotClose",
+      "msg": "Cannot close form with active participants"
+    },
+    {
+      "code": 6011,
+      "name": "switchboardError",
+      "msg": "Switchboard account parsing failed"
+    },
+    {
+      "code": 6012,
+      "name": "randomnessNotResolved",
+      "msg": "Randomness not yet resolved"
+    },
+    {
+      "code": 6013,
+      "name": "notAWinner",
+      "msg": "Participant is not a winner"
+    },
+    {
+      "code": 6014,
+      "name": "randomnessAlreadyRequested",
+      "msg": "Randomness already requested"
+    },
+    {
+      "code": 6015,
+      "name": "randomnessNotRequested",
+      "msg": "Randomness not yet requested"
+    },
+    {
+      "code": 6016,
+      "name": "randomnessNotSettled",
+      "msg": "Randomness not settled yet"
+    },
+    {
+      "code": 6017,
+      "name": "tooEarlyForFallback",
+      "msg": "Too early for emergency fallback (wait 7 days after request)"
+    },
+    {
+      "code": 6018,
+      "name": "wrongRandomnessBackend",
+      "msg": "Instruction not available for this form's randomness backend"
+    },
+    {
+      "code": 6019,
+      "name": "invalidSeedReveal",
+      "msg": "Revealed secret does not match the commitment"
+    },
+    {
+      "code": 6020,
+      "name": "revealSlotNotReached",
+      "msg": "Reveal slot has not been reached yet"
+    },
+    {
+      "code": 6021,
+      "name": "slotHashUnavailable",
+      "msg": "Slot hash for the reveal slot is not available yet or no longer available"
+    },
+    {
+      "code": 6022,
+      "name": "tooEarlyForForfeit",
+      "msg": "Too early to forfeit the reveal"
+    },
+    {
+      "code": 6023,
+      "name": "alreadyDeclaredWinner",
+      "msg": "Winner already declared"
+    },
+    {
+      "code": 6024,
+      "name": "randomnessBackendDisabled",
+      "msg": "Randomness backend is not enabled in this program build"
+    },
+    {
+      "code": 6025,
+      "name": "invalidSeedCommitment",
+      "msg": "Seed commitment is required for commit-reveal forms only"
+    },
+    {
+      "code": 6026,
+      "name": "missingTokenAccounts",
+      "msg": "Token accounts are required for token prize forms"
+    },
+    {
+      "code": 6027,
+      "name": "mathOverflow",
+      "msg": "Arithmetic overflow"
+    },
+    {
+      "code": 6028,
+      "name": "invalidPrizeSchedule",
+      "msg": "Prize tiers must be empty for equal split, or one per winner summing to 10000 bps / the prize pool"
+    },
+    {
+      "code": 6029,
+      "name": "invalidWinnerCount",
+      "msg": "Winner count must be between 1 and the max winners, and at most max participants"
+    },
+    {
+      "code": 6030,
+      "name": "formNotFunded",
+      "msg": "Prize pool has not been fully deposited yet"
+    },
+    {
+      "code": 6031,
+      "name": "formStillOpen",
+      "msg": "Form is still accepting submissions"
+    },
+    {
+      "code": 6032,
+      "name": "formCancelled",
+      "msg": "Form has been cancelled"
+    },
+    {
+      "code": 6033,
+      "name": "formFinalized",
+      "msg": "Form has been finalized"
+    },
+    {
+      "code": 6034,
+      "name": "formNotCancelled",
+      "msg": "Form has not been cancelled"
+    },
+    {
+      "code": 6035,
+      "name": "unclaimedPrizes",
+      "msg": "Cannot close form while winners can still claim their prizes"
+    },
+    {
+      "code": 6036,
+      "name": "claimWindowClosed",
+      "msg": "Claim window has closed"
+    },
+    {
+      "code": 6037,
+      "name": "claimWindowOpen",
+      "msg": "Claim window is still open"
+    },
+    {
+      "code": 6038,
+      "name": "sponsorshipExceedsPool",
+      "msg": "Sponsorship would raise the pool above the prize pool"
+    },
+    {
+      "code": 6039,
+      "name": "invalidSponsorshipAmount",
+      "msg": "Sponsorship amount must be greater than zero"
+    },
+    {
+      "code": 6040,
+      "name": "unrefundedSponsors",
+      "msg": "Sponsors have not been refunded yet"
+    },
+    {
+      "code": 6041,
+      "name": "invalidCreatorFee",
+      "msg": "Creator fee must be at most 10000 bps"
+    },
+    {
+      "code": 6042,
+      "name": "creatorFeesLocked",
+      "msg": "Creator fees can only be withdrawn once randomness is requested"
+    },
+    {
+      "code": 6043,
+      "name": "notAllowlisted",
+      "msg": "Wallet is not on the form's allowlist"
+    },
+    {
+      "code": 6044,
+      "name": "missingEmailAttestation",
+      "msg": "Email attestation instruction is missing"
+    },
+    {
+      "code": 6045,
+      "name": "invalidEmailAttestation",
+      "msg": "Email attestation does not match the verifier, form, wallet or email"
+    },
+    {
+      "code": 6046,
+      "name": "emailAttestationExpired",
+      "msg": "Email attestation has expired"
+    },
+    {
+      "code": 6047,
+      "name": "submissionsStillOpen",
+      "msg": "Email claims can only be closed once submissions are closed"
+    },
+    {
+      "code": 6048,
+      "name": "openEmailClaims",
+      "msg": "Close every email claim before closing the form"
+    },
+    {
+      "code": 6049,
+      "name": "schemaVersionMismatch",
+      "msg": "Responses were given for a different schema version"
+    },
+    {
+      "code": 6050,
+      "name": "invalidSchema",
+      "msg": "Schema needs 1 to 32 valid questions and a response cap of at most 10240 bytes"
+    },
+    {
+      "code": 6051,
+      "name": "schemaLocked",
+      "msg": "Schema can only be created before submissions open"
+    },
+    {
+      "code": 6052,
+      "name": "invalidAnswers",
+      "msg": "Answers do not match the form schema"
+    },
+    {
+      "code": 6053,
+      "name": "responseTooLarge",
+      "msg": "Response exceeds the form's size cap"
+    },
+    {
+      "code": 6054,
+      "name": "wrongResponseEncryption",
+      "msg": "Encrypted forms only take encrypted responses, and plaintext forms plaintext ones"
+    },
+    {
+      "code": 6055,
+      "name": "invalidEnvelope",
+      "msg": "Encrypted response envelope is malformed"
+    },
+    {
+      "code": 6056,
+      "name": "notAQuiz",
+      "msg": "Form is not a quiz"
+    },
+    {
+      "code": 6057,
+      "name": "answerKeyNotRevealed",
+      "msg": "Answer key has not been revealed yet"
+    },
+    {
+      "code": 6058,
+      "name": "answerKeyAlreadyRevealed",
+      "msg": "Answer key already revealed"
+    },
+    {
+      "code": 6059,
+      "name": "invalidAnswerKey",
+      "msg": "Answer key does not match the commitment"
+    },
+    {
+      "code": 6060,
+      "name": "eligibilityWindowOpen",
+      "msg": "Participants can still prove eligibility"
+    },
+    {
+      "code": 6061,
+      "name": "eligibilityWindowClosed",
+      "msg": "Eligibility window has closed"
+    },
+    {
+      "code": 6062,
+      "name": "invalidQuizAnswers",
+      "msg": "Answers do not match the participant's commitment"
+    },
+    {
+      "code": 6063,
+      "name": "incorrectAnswers",
+      "msg": "Answers do not match the answer key"
+    },
+    {
+      "code": 6064,
+      "name": "alreadyEligible",
+      "msg": "Participant already marked eligible"
+    },
+    {
+      "code": 6065,
+      "name": "notEligible",
+      "msg": "Participant is not eligible to win"
+    },
+    {
+      "code": 6066,
+      "name": "invalidWinnerSelection",
+      "msg": "Skill-ranked forms must be quizzes and cannot use the admin-declared backend"
+    },
+    {
+      "code": 6067,
+      "name": "rankedFormOnly",
+      "msg": "Instruction is only available for skill-ranked forms"
+    },
+    {
+      "code": 6068,
+      "name": "lotteryFormOnly",
+      "msg": "Instruction is not available for skill-ranked forms"
+    },
+    {
+      "code": 6069,
+      "name": "missingLeaderboard",
+      "msg": "Leaderboard account is required for skill-ranked forms"
+    },
+    {
+      "code": 6070,
+      "name": "leaderboardFull",
+      "msg": "Too many participants tied for the last prize"
+    },
+    {
+      "code": 6071,
+      "name": "invalidLastParticipant",
+      "msg": "Last participant account is missing or does not hold the last index"
+    },
+    {
+      "code": 6072,
+      "name": "rentReserveExhausted",
+      "msg": "Rent reserve cannot cover this relayed submission"
+    },
+    {
+      "code": 6073,
+      "name": "missingGateAccounts",
+      "msg": "Gate token account (and metadata for collections) is required for this form"
+    },
+    {
+      "code": 6074,
+      "name": "participationGateNotMet",
+      "msg": "Wallet does not hold the token or NFT required by this form"
+    },
+    {
+      "code": 6075,
+      "name": "notPendingAuthority",
+      "msg": "Signer is not the pending authority"
+    },
+    {
+      "code": 6076,
+      "name": "invalidPermissions",
+      "msg": "Operator permissions contain unknown bits"
+    },
+    {
+      "code": 6077,
+      "name": "tooManyOperators",
+      "msg": "Form already has the maximum number of operators"
+    },
+    {
+      "code": 6078,
+      "name": "invalidDeadline",
+      "msg": "New deadline must be later than the current one"
+    },
+    {
+      "code": 6079,
+      "name": "programPaused",
+      "msg": "Program is paused"
+    },
+    {
+      "code": 6080,
+      "name": "invalidConfig",
+      "msg": "Config needs a fee of at most 10000 bps, 1 to 10 max winners and a non-negative max duration"
+    },
+    {
+      "code": 6081,
+      "name": "exceedsProtocolLimits",
+      "msg": "Form exceeds the program's prize pool, winner or duration limits"
+    },
+    {
+      "code": 6082,
+      "name": "insufficientTreasury",
+      "msg": "Treasury does not hold enough to withdraw this amount"
+    },
+    {
+      "code": 6083,
+      "name": "responsesLocked",
+      "msg": "Responses can only be closed once the form is settled or cancelled"
+    },
+    {
+      "code": 6084,
+      "name": "openResponses",
+      "msg": "Close every response before closing the form"
+    },
+    {
+      "code": 6085,
+      "name": "staleRandomness",
+      "msg": "Randomness account must be committed in the previous slot"
+    },
+    {
+      "code": 6086,
+      "name": "cannotCancelAfterReveal",
+      "msg": "Form cannot be cancelled once the answer key is revealed"
     }
-  ];
-  errors: [
-    { code: 6000; name: "FormInactive"; msg: "Form is not active" },
-    { code: 6001; name: "AlreadyDistributed"; msg: "Prizes already distributed" },
-    { code: 6002; name: "PrizePoolFilled"; msg: "Prize pool already filled" },
-    { code: 6003; name: "DeadlinePassed"; msg: "Deadline has passed" },
-    { code: 6004; name: "DeadlineNotReached"; msg: "Deadline has not been reached yet" },
-    { code: 6005; name: "MaxParticipantsReached"; msg: "Maximum participants reached" },
-    { code: 6006; name: "Unauthorized"; msg: "Unauthorized" },
-    { code: 6007; name: "NoParticipants"; msg: "No participants to distribute to" },
-    { code: 6008; name: "NotDistributed"; msg: "Prizes not distributed yet" },
-    { code: 6009; name: "AlreadyClaimed"; msg: "Prize already claimed" },
-    { code: 6010; name: "CannotClose"; msg: "Cannot close form with active participants" },
-    { code: 6011; name: "NotAWinner"; msg: "Participant is not a winner" }
-  ];
-};
-
-export const IDL: SolanaForm = {
-  version: "0.1.0",
-  name: "solana_form",
-  instructions: [
+  ],
+  "types": [
     {
-      name: "initializeForm",
-      accounts: [
-        { name: "form", isMut: true, isSigner: false },
-        { name: "authority", isMut: true, isSigner: true },
-        { name: "systemProgram", isMut: false, isSigner: false }
+      "name": "answer",
+      "docs": [
+        "Answer to a question, variant must match the question kind"
       ],
-      args: [
-        { name: "formId", type: "string" },
-        { name: "prizePool", type: "u64" },
-        { name: "deadline", type: "i64" },
-        { name: "maxParticipants", type: "u32" }
-      ]
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "skipped"
+          },
+          {
+            "name": "choice",
+            "fields": [
+              "u8"
+            ]
+          },
+          {
+            "name": "integer",
+            "fields": [
+              "i64"
+            ]
+          },
+          {
+            "name": "shortText",
+            "fields": [
+              "string"
+            ]
+          }
+        ]
+      }
     },
     {
-      name: "depositPrize",
-      accounts: [
-        { name: "form", isMut: true, isSigner: false },
-        { name: "authority", isMut: true, isSigner: true },
-        { name: "systemProgram", isMut: false, isSigner: false }
+      "name": "config",
+      "docs": [
+        "Program-wide settings, a single account at [b\"config\"] created by the upgrade authority"
       ],
-      args: []
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "protocolFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "treasury",
+            "type": "pubkey"
+          },
+          {
+            "name": "maxPrizePool",
+            "type": "u64"
+          },
+          {
+            "name": "maxWinners",
+            "type": "u32"
+          },
+          {
+            "name": "maxDuration",
+            "type": "i64"
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      name: "submitForm",
-      accounts: [
-        { name: "form", isMut: true, isSigner: false },
-        { name: "participant", isMut: true, isSigner: false },
-        { name: "user", isMut: true, isSigner: true },
-        { name: "systemProgram", isMut: false, isSigner: false }
+      "name": "emailClaim",
+      "docs": [
+        "One per email hash and form, a second submission with the same email fails at init"
       ],
-      args: [{ name: "emailHash", type: { array: ["u8", 32] } }]
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "form",
+            "type": "pubkey"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "rentSponsored",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "form",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "formId",
+            "type": "string"
+          },
+          {
+            "name": "prizePool",
+            "type": "u64"
+          },
+          {
+            "name": "collectedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "deadline",
+            "type": "i64"
+          },
+          {
+            "name": "maxParticipants",
+            "type": "u32"
+          },
+          {
+            "name": "participantCount",
+            "type": "u32"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "formStatus"
+              }
+            }
+          },
+          {
+            "name": "usesFallback",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "randomnessBackend",
+            "type": {
+              "defined": {
+                "name": "randomnessBackend"
+              }
+            }
+          },
+          {
+            "name": "randomnessAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "randomValue",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "randomnessRequestTime",
+            "type": "i64"
+          },
+          {
+            "name": "winners",
+            "type": {
+              "vec": "u32"
+            }
+          },
+          {
+            "name": "seedCommitment",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "entropyAccumulator",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "revealSlot",
+            "type": "u64"
+          },
+          {
+            "name": "revealForfeited",
+            "type": "bool"
+          },
+          {
+            "name": "prizeMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "prizeSchedule",
+            "type": {
+              "defined": {
+                "name": "prizeSchedule"
+              }
+            }
+          },
+          {
+            "name": "winnerCount",
+            "type": "u32"
+          },
+          {
+            "name": "claimDeadline",
+            "type": "i64"
+          },
+          {
+            "name": "claimedCount",
+            "type": "u32"
+          },
+          {
+            "name": "claimedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "allowOverfunding",
+            "type": "bool"
+          },
+          {
+            "name": "sponsoredAmount",
+            "type": "u64"
+          },
+          {
+            "name": "entryFee",
+            "type": "u64"
+          },
+          {
+            "name": "creatorFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "entryFeesCollected",
+            "type": "u64"
+          },
+          {
+            "name": "creatorFees",
+            "type": "u64"
+          },
+          {
+            "name": "allowlistRoot",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "emailVerifier",
+            "type": "pubkey"
+          },
+          {
+            "name": "emailClaimCount",
+            "type": "u32"
+          },
+          {
+            "name": "questionsHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "schemaVersion",
+            "type": "u16"
+          },
+          {
+            "name": "responseEncryptionKey",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "answerKeyCommitment",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "answerKey",
+            "type": "bytes"
+          },
+          {
+            "name": "answerKeyRevealed",
+            "type": "bool"
+          },
+          {
+            "name": "eligibilityDeadline",
+            "type": "i64"
+          },
+          {
+            "name": "eligibleCount",
+            "type": "u32"
+          },
+          {
+            "name": "winnerSelection",
+            "type": {
+              "defined": {
+                "name": "winnerSelection"
+              }
+            }
+          },
+          {
+            "name": "rentReserve",
+            "type": "u64"
+          },
+          {
+            "name": "participationGate",
+            "type": {
+              "defined": {
+                "name": "participationGate"
+              }
+            }
+          },
+          {
+            "name": "pendingAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "operators",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "operator"
+                }
+              }
+            }
+          },
+          {
+            "name": "protocolFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "responseCount",
+            "type": "u32"
+          }
+        ]
+      }
     },
     {
-      name: "distributePrizes",
-      accounts: [
-        { name: "form", isMut: true, isSigner: false },
-        { name: "authority", isMut: true, isSigner: true }
+      "name": "formSchema",
+      "docs": [
+        "Question set of a form, answers in Response accounts are checked against it"
       ],
-      args: []
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "form",
+            "type": "pubkey"
+          },
+          {
+            "name": "schemaVersion",
+            "type": "u16"
+          },
+          {
+            "name": "maxResponseSize",
+            "type": "u32"
+          },
+          {
+            "name": "questions",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "question"
+                }
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      name: "checkAndClaimPrize",
-      accounts: [
-        { name: "form", isMut: true, isSigner: false },
-        { name: "participant", isMut: true, isSigner: false },
-        { name: "winner", isMut: true, isSigner: false },
-        { name: "systemProgram", isMut: false, isSigner: false }
+      "name": "formStatus",
+      "docs": [
+        "Form lifecycle, only changed through `transition`"
       ],
-      args: []
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "draft"
+          },
+          {
+            "name": "funded"
+          },
+          {
+            "name": "open"
+          },
+          {
+            "name": "closed"
+          },
+          {
+            "name": "randomnessPending"
+          },
+          {
+            "name": "settled"
+          },
+          {
+            "name": "cancelled"
+          },
+          {
+            "name": "finalized"
+          }
+        ]
+      }
+    },
+    {
+      "name": "formStatusChanged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "form",
+            "type": "pubkey"
+          },
+          {
+            "name": "from",
+            "type": {
+              "defined": {
+                "name": "formStatus"
+              }
+            }
+          },
+          {
+            "name": "to",
+            "type": {
+              "defined": {
+                "name": "formStatus"
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      name: "closeForm",
-      accounts: [
-        { name: "form", isMut: true, isSigner: false },
-        { name: "authority", isMut: true, isSigner: true }
+      "name": "leaderboard",
+      "docs": [
+        "Top scores of a skill-ranked form, sorted by score, ties are broken at settlement"
       ],
-      args: []
-    }
-  ],
-  accounts: [
-    {
-      name: "form",
-      type: {
-        kind: "struct",
-        fields: [
-          { name: "authority", type: "publicKey" },
-          { name: "formId", type: "string" },
-          { name: "prizePool", type: "u64" },
-          { name: "collectedAmount", type: "u64" },
-          { name: "deadline", type: "i64" },
-          { name: "maxParticipants", type: "u32" },
-          { name: "participantCount", type: "u32" },
-          { name: "isActive", type: "bool" },
-          { name: "isDistributed", type: "bool" },
-          { name: "bump", type: "u8" },
-          { name: "randomSeed", type: "u64" }
-        ]
-      }
-    },
-    {
-      name: "participant",
-      type: {
-        kind: "struct",
-        fields: [
-          { name: "wallet", type: "publicKey" },
-          { name: "form", type: "publicKey" },
-          { name: "emailHash", type: { array: ["u8", 32] } },
-          { name: "timestamp", type: "i64" },
-          { name: "participantIndex", type: "u32" },
-          { name: "isWinner", type: "bool" },
-          { name: "claimed", type: "bool" },
-          { name: "bump", type: "u8" }
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "form",
+            "type": "pubkey"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "leaderboardEntry"
+                }
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "leaderboardEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "participantIndex",
+            "type": "u32"
+          },
+          {
+            "name": "score",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "operator",
+      "docs": [
+        "Wallet allowed to run some admin actions on behalf of the authority"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "key",
+            "type": "pubkey"
+          },
+          {
+            "name": "permissions",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "participant",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "form",
+            "type": "pubkey"
+          },
+          {
+            "name": "emailHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          },
+          {
+            "name": "participantIndex",
+            "type": "u32"
+          },
+          {
+            "name": "isWinner",
+            "type": "bool"
+          },
+          {
+            "name": "claimed",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "entryFeePaid",
+            "type": "u64"
+          },
+          {
+            "name": "allocation",
+            "type": "u64"
+          },
+          {
+            "name": "responsesHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "schemaVersion",
+            "type": "u16"
+          },
+          {
+            "name": "eligible",
+            "type": "bool"
+          },
+          {
+            "name": "eligibleIndex",
+            "type": "u32"
+          },
+          {
+            "name": "score",
+            "type": "u32"
+          },
+          {
+            "name": "rentSponsored",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "participationGate",
+      "docs": [
+        "Holding required to submit, checked in submit_form"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "none"
+          },
+          {
+            "name": "tokenBalance",
+            "fields": [
+              {
+                "name": "mint",
+                "type": "pubkey"
+              },
+              {
+                "name": "minAmount",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "nftCollection",
+            "fields": [
+              {
+                "name": "collection",
+                "type": "pubkey"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "prizeSchedule",
+      "docs": [
+        "Prize per winner rank, rank 0 is the first winner drawn"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "split",
+            "type": {
+              "defined": {
+                "name": "prizeSplit"
+              }
+            }
+          },
+          {
+            "name": "tiers",
+            "type": {
+              "vec": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "prizeSplit",
+      "docs": [
+        "How the collected pool is split between ranked winners"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "equal"
+          },
+          {
+            "name": "basisPoints"
+          },
+          {
+            "name": "fixedAmounts"
+          }
+        ]
+      }
+    },
+    {
+      "name": "question",
+      "docs": [
+        "A question of a form schema"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "kind",
+            "type": {
+              "defined": {
+                "name": "questionKind"
+              }
+            }
+          },
+          {
+            "name": "required",
+            "docs": [
+              "Skipped answers are rejected for required questions"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "questionKind",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "choice",
+            "fields": [
+              {
+                "name": "options",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "integer",
+            "fields": [
+              {
+                "name": "min",
+                "type": "i64"
+              },
+              {
+                "name": "max",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "shortText",
+            "fields": [
+              {
+                "name": "maxLen",
+                "type": "u16"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "randomnessBackend",
+      "docs": [
+        "Where a form's winners come from, chosen once at initialize_form"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "slotHash"
+          },
+          {
+            "name": "switchboard"
+          },
+          {
+            "name": "commitReveal"
+          },
+          {
+            "name": "adminDeclared"
+          }
+        ]
+      }
+    },
+    {
+      "name": "response",
+      "docs": [
+        "Answers of one participant, sized to its payload and reallocated on update"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "form",
+            "type": "pubkey"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "schemaVersion",
+            "type": "u16"
+          },
+          {
+            "name": "payload",
+            "type": {
+              "defined": {
+                "name": "responsePayload"
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "responsePayload",
+      "docs": [
+        "Content of a Response, depending on whether the form encrypts responses"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "answers",
+            "fields": [
+              {
+                "vec": {
+                  "defined": {
+                    "name": "answer"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "encrypted",
+            "fields": [
+              "bytes"
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "sponsorship",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "form",
+            "type": "pubkey"
+          },
+          {
+            "name": "sponsor",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "treasury",
+      "docs": [
+        "Holds the protocol fees at [b\"treasury\"], SOL as lamports and tokens in token accounts it owns"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "winnerSelection",
+      "docs": [
+        "How winners are picked among the draw pool"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "lottery"
+          },
+          {
+            "name": "skillRanked"
+          }
         ]
       }
     }
-  ],
-  errors: [
-    { code: 6000, name: "FormInactive", msg: "Form is not active" },
-    { code: 6001, name: "AlreadyDistributed", msg: "Prizes already distributed" },
-    { code: 6002, name: "PrizePoolFilled", msg: "Prize pool already filled" },
-    { code: 6003, name: "DeadlinePassed", msg: "Deadline has passed" },
-    { code: 6004, name: "DeadlineNotReached", msg: "Deadline has not been reached yet" },
-    { code: 6005, name: "MaxParticipantsReached", msg: "Maximum participants reached" },
-    { code: 6006, name: "Unauthorized", msg: "Unauthorized" },
-    { code: 6007, name: "NoParticipants", msg: "No participants to distribute to" },
-    { code: 6008, name: "NotDistributed", msg: "Prizes not distributed yet" },
-    { code: 6009, name: "AlreadyClaimed", msg: "Prize already claimed" },
-    { code: 6010, name: "CannotClose", msg: "Cannot close form with active participants" },
-    { code: 6011, name: "NotAWinner", msg: "Participant is not a winner" }
   ]
-};
\ No newline at end of file
+};
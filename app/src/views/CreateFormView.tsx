@@ -3,7 +3,7 @@ import { useState, type FC } from 'react';
 import { PublicKey, SystemProgram, LAMPORTS_PER_SOL } from '@solana/web3.js';
 import { Program, AnchorProvider, BN } from '@coral-xyz/anchor';
 import idl from '../idl/solana_form.json';
-import { USE_DEMO_MODE, PROGRAM_ID } from '../constants';
+import { USE_DEMO_MODE } from '../constants';
 import type { View, CreateFormData } from '../types';
 
 interface CreateFormViewProps {
@@ -60,11 +60,7 @@ const CreateFormView: FC<CreateFormViewProps> = ({
         const provider = new AnchorProvider(connection, wallet, {
           commitment: 'confirmed',
         });
-        const program = new Program(
-          idl as any,
-          PROGRAM_ID,
-          provider
-        );
+        const program = new Program(idl as any, provider);
 
         const formId = `form-${Date.now()}`;
         const [formPda] = await PublicKey.findProgramAddressSync(
@@ -73,17 +69,41 @@ const CreateFormView: FC<CreateFormViewProps> = ({
         );
 
         const deadline = Math.floor(Date.now() / 1000) + parseInt(formData.duration) * 3600;
+        const maxParticipants = parseInt(formData.maxParticipants);
+
+        // Commit to the question set on-chain
+        const questionsDigest = await crypto.subtle.digest(
+          'SHA-256',
+          new TextEncoder().encode(JSON.stringify(formData.questions))
+        );
+        const questionsHash = Array.from(new Uint8Array(questionsDigest));
 
         await program.methods
           .initializeForm(
             formId,
             new BN(prizeAmount * LAMPORTS_PER_SOL),
             new BN(deadline),
-            parseInt(formData.maxParticipants)
+            maxParticipants,
+            Math.min(10, maxParticipants), // winner count
+            { slotHash: {} },
+            null, // seed commitment, commit-reveal only
+            { split: { equal: {} }, tiers: [] },
+            false, // allow overfunding
+            new BN(0), // entry fee
+            0, // creator fee bps
+            null, // allowlist root
+            null, // email verifier
+            questionsHash,
+            1, // schema version
+            null, // answer key commitment, quizzes only
+            { lottery: {} }
           )
           .accounts({
             form: formPda,
             authority: wallet.publicKey,
+            prizeMint: null,
+            vault: null,
+            tokenProgram: null,
             systemProgram: SystemProgram.programId,
           })
           .rpc();
@@ -94,6 +114,10 @@ const CreateFormView: FC<CreateFormViewProps> = ({
           .accounts({
             form: formPda,
             authority: wallet.publicKey,
+            prizeMint: null,
+            authorityTokenAccount: null,
+            vault: null,
+            tokenProgram: null,
             systemProgram: SystemProgram.programId,
           })
           .rpc();
@@ -4,7 +4,7 @@ import { Program, AnchorProvider } from '@coral-xyz/anchor';
 import { LAMPORTS_PER_SOL, SystemProgram } from '@solana/web3.js';
 import idl from '../idl/solana_form.json';
 import { type SolanaForm } from '../idl/solana_form';
-import { USE_DEMO_MODE, PROGRAM_ID } from '../constants';
+import { USE_DEMO_MODE } from '../constants';
 import StatCard from '../components/StatCard';
 
 interface DashboardViewProps {
@@ -33,29 +33,25 @@ const DashboardView: FC<DashboardViewProps> = ({ wallet, connection }) => {
         const provider = new AnchorProvider(connection, wallet, {
           preflightCommitment: 'processed',
         });
-        const program = new Program<SolanaForm>(
-          idl as any,
-          PROGRAM_ID,
-          provider
-        ) as any;
+        const program = new Program<SolanaForm>(idl as any, provider) as any;
 
         // Fetch forms created by the user
         const formAccounts = await program.account.form.all([
           { 
             memcmp: {
-              offset: 8, // Discriminator
+              offset: 8, // Discriminator, authority comes first
               bytes: wallet.publicKey.toBase58(),
             },
           },
         ]);
-        const formattedForms = formAccounts.map((form) => ({
+        const formattedForms = formAccounts.map((form: any) => ({
           publicKey: form.publicKey,
           id: form.account.formId,
           title: form.account.formId.replace(/-/g, ' '),
           participants: form.account.participantCount,
           prizePool: form.account.prizePool.toNumber() / LAMPORTS_PER_SOL,
           status:
-            form.account.endTime.toNumber() > Date.now() / 1000
+            form.account.deadline.toNumber() > Date.now() / 1000
               ? 'active'
               : 'ended',
         }));
@@ -65,25 +61,24 @@ const DashboardView: FC<DashboardViewProps> = ({ wallet, connection }) => {
         const participantAccounts = await program.account.participant.all([
           {
             memcmp: {
-              offset: 8 + 32, // Discriminator + Form Pubkey
+              offset: 8, // Discriminator, wallet comes first
               bytes: wallet.publicKey.toBase58(),
             },
           },
         ]);
 
         const submissions = await Promise.all(
-          participantAccounts.map(async (p) => {
+          participantAccounts.map(async (p: any) => {
             const formAccount = await program.account.form.fetch(
               p.account.form
             );
             const canClaim =
-              formAccount.endTime.toNumber() < Date.now() / 1000 &&
-              !p.account.claimed;
+              'settled' in formAccount.status && !p.account.claimed;
             return {
               formPublicKey: p.account.form,
               participantPublicKey: p.publicKey,
               formTitle: formAccount.formId.replace(/-/g, ' '),
-              submittedAt: p.account.submissionTime.toNumber() * 1000,
+              submittedAt: p.account.timestamp.toNumber() * 1000,
               status: p.account.claimed
                 ? 'Claimed'
                 : canClaim
@@ -127,18 +122,19 @@ const DashboardView: FC<DashboardViewProps> = ({ wallet, connection }) => {
         }, 2000);
       } else {
         const provider = new AnchorProvider(connection, wallet, {});
-        const program = new Program<SolanaForm>(
-          idl as any,
-          PROGRAM_ID,
-          provider
-        ) as any;
+        const program = new Program<SolanaForm>(idl as any, provider) as any;
 
         await program.methods
-          .checkAndClaimPrize()
+          .claimPrize()
           .accounts({
             form: submission.formPublicKey,
             participant: submission.participantPublicKey,
             winner: wallet.publicKey,
+            prizeMint: null,
+            vault: null,
+            winnerTokenAccount: null,
+            treasuryTokenAccount: null,
+            tokenProgram: null,
             systemProgram: SystemProgram.programId,
           })
           .rpc();
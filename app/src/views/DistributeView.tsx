@@ -1,11 +1,11 @@
 import { useState, type FC } from 'react';
 import { AnchorProvider, Program } from '@coral-xyz/anchor';
 import { AlertCircle, CheckCircle, Loader, Trophy } from 'lucide-react';
-import { USE_DEMO_MODE, PROGRAM_ID } from '../constants';
+import { USE_DEMO_MODE } from '../constants';
 import type { View, FormData } from '../types';
 import idl from '../idl/solana_form.json';
 import { type SolanaForm } from '../idl/solana_form';
-import { SystemProgram } from '@solana/web3.js';
+import { SYSVAR_SLOT_HASHES_PUBKEY } from '@solana/web3.js';
 
 interface DistributeViewProps {
   form: FormData | null;
@@ -14,31 +14,6 @@ interface DistributeViewProps {
   setView: (view: View) => void;
 }
 
-// Helper function to deterministically select winners based on a seed
-const calculateWinners = (
-  participants: any[],
-  seed: Buffer,
-  maxWinners: number
-) => {
-  if (participants.length === 0) return [];
-
-  // A simple pseudo-random number generator using the seed
-  let random = new DataView(seed.buffer).getUint32(0, true);
-  const shuffle = (arr: any[]) => {
-    for (let i = arr.length - 1; i > 0; i--) {
-      random = (random * 1664525 + 1013904223) | 0; // LCG
-      const j = Math.abs(random) % (i + 1);
-      [arr[i], arr[j]] = [arr[j], arr[i]];
-    }
-    return arr;
-  };
-
-  const shuffled = shuffle([...participants]);
-  return shuffled
-    .slice(0, maxWinners)
-    .map((p) => p.account.user.toBase58());
-};
-
 const DistributeView: FC<DistributeViewProps> = ({
   form,
   wallet,
@@ -80,36 +55,31 @@ const DistributeView: FC<DistributeViewProps> = ({
       } else {
         // Production: Call distribute_prizes instruction
         const provider = new AnchorProvider(connection, wallet, {});
-        const program = new Program<SolanaForm>(
-          idl as any,
-          PROGRAM_ID,
-          provider
-        ) as any;
+        const program = new Program<SolanaForm>(idl as any, provider) as any;
 
         await program.methods
           .distributePrizes()
           .accounts({
             form: form.publicKey,
             authority: wallet.publicKey,
-            systemProgram: SystemProgram.programId, // Added for recent Anchor versions
+            slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+            leaderboard: null,
           })
           .rpc();
 
         // Fetch all participants for this form
         const participantAccounts = await program.account.participant.all([
-          { memcmp: { offset: 8, bytes: form.publicKey!.toBase58() } },
+          { memcmp: { offset: 8 + 32, bytes: form.publicKey!.toBase58() } },
         ]);
 
-        // Fetch the updated form account to get the random seed
+        // The program stores the drawn participant indices on the form
         const formAccount = await program.account.form.fetch(form.publicKey);
-        const seed = Buffer.from(formAccount.randomSeed);
-
-        // Calculate winners off-chain using the on-chain seed
-        const winnerAddresses = calculateWinners(
-          participantAccounts,
-          seed,
-          10 // Max winners
-        );
+        const winnerAddresses = formAccount.winners.map((index: number) => {
+          const winner = participantAccounts.find(
+            (p: any) => p.account.participantIndex === index
+          );
+          return winner ? winner.account.wallet.toBase58() : `#${index}`;
+        });
 
         setWinners(winnerAddresses);
         setDistributionStep('complete');
@@ -163,7 +133,7 @@ const DistributeView: FC<DistributeViewProps> = ({
                   The program generates a random seed from blockchain data.
                 </li>
                 <li>
-                  This app uses the seed to deterministically select winners.
+                  The program uses the seed to draw the winners on-chain.
                 </li>
                 <li>Winners can then claim their prizes from the dashboard.</li>
                 <li>Each winner gets an equal share of the prize pool.</li>
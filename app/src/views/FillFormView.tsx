@@ -1,11 +1,11 @@
 // app/src/views/FillFormView.tsx
 import { useState, type FC } from 'react';
-import { AnchorProvider, Program, type Wallet as AnchorWallet } from '@coral-xyz/anchor';
+import { AnchorProvider, BN, Program, type Wallet as AnchorWallet } from '@coral-xyz/anchor';
 import { CheckCircle } from 'lucide-react';
 import { PublicKey, SystemProgram, type Connection } from '@solana/web3.js';
 import { type WalletContextState } from '@solana/wallet-adapter-react';
 import idl from '../idl/solana_form.json';
-import { USE_DEMO_MODE, PROGRAM_ID } from '../constants';
+import { USE_DEMO_MODE } from '../constants';
 import type { View, FormData } from '../types';
 
 interface FillFormViewProps {
@@ -88,11 +88,7 @@ const FillFormView: FC<FillFormViewProps> = ({
         const provider = new AnchorProvider(connection, anchorWallet, {
           commitment: 'confirmed',
         });
-        const program = new Program(
-          idl as any,
-          // PROGRAM_ID,
-          provider
-        );
+        const program = new Program(idl as any, provider) as any;
 
         if (!form.publicKey) {
           throw new Error('Form does not have a publicKey');
@@ -107,12 +103,37 @@ const FillFormView: FC<FillFormViewProps> = ({
           program.programId
         );
 
+        // Commit to the answers for the form's current question set
+        const formAccount = await program.account.form.fetch(form.publicKey);
+        const answersDigest = await crypto.subtle.digest(
+          'SHA-256',
+          encoder.encode(
+            JSON.stringify(form.questions.map((_, index) => answers[index]))
+          )
+        );
+        const responsesHash = Array.from(new Uint8Array(answersDigest));
+
         await program.methods
-          .submitForm(emailHash)
+          .submitForm(
+            emailHash,
+            [], // allowlist proof
+            new BN(0), // allowlist allocation
+            new BN(0), // email attestation expiry
+            responsesHash,
+            formAccount.schemaVersion
+          )
           .accounts({
             form: form.publicKey,
             participant: participantPda,
             user: wallet.publicKey,
+            payer: wallet.publicKey,
+            prizeMint: null,
+            userTokenAccount: null,
+            vault: null,
+            tokenProgram: null,
+            gateTokenAccount: null,
+            gateMetadata: null,
+            instructionsSysvar: null,
             systemProgram: SystemProgram.programId,
           })
           .rpc();
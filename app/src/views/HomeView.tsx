@@ -62,7 +62,7 @@ const HomeView: FC<HomeViewProps> = ({ setView, setSelectedForm }) => {
             deadline: form.account.deadline.toNumber() * 1000,
             maxParticipants: form.account.maxParticipants,
             questions: ['Question 1', 'Question 2', 'Question 3'], // Placeholder
-            isDistributed: form.account.isDistributed,
+            isDistributed: 'settled' in form.account.status,
             publicKey: form.publicKey,
           }));
 
@@ -0,0 +1,309 @@
+// client/src/lib.rs
+// Encrypts answers to a form's response key on submission and decrypts them for the creator.
+// The envelope layout is defined in programs/solanaform/src/envelope.rs.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{
+    system_program, AnchorDeserialize, AnchorSerialize, InstructionData, ToAccountMetas,
+};
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use hkdf::Hkdf;
+use rand_core::{OsRng, RngCore};
+use sha2::Sha256;
+use solanaform::envelope::{
+    ENVELOPE_HEADER_LEN, ENVELOPE_VERSION, EPHEMERAL_KEY_LEN, NONCE_LEN, TAG_LEN,
+};
+use solanaform::{Answer, Response, ResponsePayload};
+use x25519_dalek::{EphemeralSecret, PublicKey};
+
+pub use x25519_dalek::StaticSecret;
+
+const KEY_INFO: &[u8] = b"solana_form:response:v1";
+
+#[derive(Debug, thiserror::Error)]
+pub enum ClientError {
+    #[error("envelope is malformed")]
+    InvalidEnvelope,
+    #[error("key exchange produced a non-contributory shared secret")]
+    WeakKey,
+    #[error("decryption failed, wrong key or envelope not bound to this form and wallet")]
+    DecryptionFailed,
+    #[error("failed to encode or decode answers: {0}")]
+    Encoding(#[from] std::io::Error),
+}
+
+// ============ KEYS ============
+
+/// New creator key pair, the public half goes into create_form_schema
+pub fn generate_response_key() -> (StaticSecret, [u8; 32]) {
+    let secret = StaticSecret::random_from_rng(OsRng);
+    let public = PublicKey::from(&secret);
+    (secret, public.to_bytes())
+}
+
+/// XChaCha20-Poly1305 key for one envelope
+fn envelope_key(
+    shared_secret: &[u8; 32],
+    ephemeral_key: &[u8; 32],
+    recipient_key: &[u8; 32],
+) -> [u8; 32] {
+    let salt = [ephemeral_key.as_ref(), recipient_key.as_ref()].concat();
+    let mut key = [0u8; 32];
+    Hkdf::<Sha256>::new(Some(&salt), shared_secret)
+        .expand(KEY_INFO, &mut key)
+        .expect("32 bytes is a valid HKDF-SHA256 output length");
+    key
+}
+
+/// Binds the envelope to its Response, so it can't be copied into another participant's
+fn associated_data(form: &Pubkey, wallet: &Pubkey) -> Vec<u8> {
+    [form.as_ref(), wallet.as_ref()].concat()
+}
+
+// ============ ENCRYPT / DECRYPT ============
+
+/// Seal `answers` to the form's response key
+pub fn encrypt_answers(
+    recipient_key: &[u8; 32],
+    form: &Pubkey,
+    wallet: &Pubkey,
+    answers: &[Answer],
+) -> Result<Vec<u8>, ClientError> {
+    let ephemeral_secret = EphemeralSecret::random_from_rng(OsRng);
+    let ephemeral_key = PublicKey::from(&ephemeral_secret).to_bytes();
+    let shared_secret = ephemeral_secret.diffie_hellman(&PublicKey::from(*recipient_key));
+    if !shared_secret.was_contributory() {
+        return Err(ClientError::WeakKey);
+    }
+
+    let key = envelope_key(shared_secret.as_bytes(), &ephemeral_key, recipient_key);
+    let mut nonce = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce);
+
+    let plaintext = answers.to_vec().try_to_vec()?;
+    let ciphertext = XChaCha20Poly1305::new(&key.into())
+        .encrypt(
+            XNonce::from_slice(&nonce),
+            Payload {
+                msg: &plaintext,
+                aad: &associated_data(form, wallet),
+            },
+        )
+        .map_err(|_| ClientError::InvalidEnvelope)?;
+
+    let mut envelope = Vec::with_capacity(ENVELOPE_HEADER_LEN + ciphertext.len());
+    envelope.push(ENVELOPE_VERSION);
+    envelope.extend_from_slice(&ephemeral_key);
+    envelope.extend_from_slice(&nonce);
+    envelope.extend_from_slice(&ciphertext);
+    Ok(envelope)
+}
+
+/// Open an envelope with the creator's secret key
+pub fn decrypt_answers(
+    secret_key: &StaticSecret,
+    form: &Pubkey,
+    wallet: &Pubkey,
+    envelope: &[u8],
+) -> Result<Vec<Answer>, ClientError> {
+    if envelope.len() < ENVELOPE_HEADER_LEN + TAG_LEN || envelope[0] != ENVELOPE_VERSION {
+        return Err(ClientError::InvalidEnvelope);
+    }
+    let (ephemeral_key, rest) = envelope[1..].split_at(EPHEMERAL_KEY_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    let ephemeral_key: [u8; 32] = ephemeral_key.try_into().unwrap();
+
+    let shared_secret = secret_key.diffie_hellman(&PublicKey::from(ephemeral_key));
+    if !shared_secret.was_contributory() {
+        return Err(ClientError::WeakKey);
+    }
+
+    let recipient_key = PublicKey::from(secret_key).to_bytes();
+    let key = envelope_key(shared_secret.as_bytes(), &ephemeral_key, &recipient_key);
+    let plaintext = XChaCha20Poly1305::new(&key.into())
+        .decrypt(
+            XNonce::from_slice(nonce),
+            Payload {
+                msg: ciphertext,
+                aad: &associated_data(form, wallet),
+            },
+        )
+        .map_err(|_| ClientError::DecryptionFailed)?;
+
+    Ok(Vec::<Answer>::try_from_slice(&plaintext)?)
+}
+
+// ============ SUBMISSION ============
+
+pub fn config_address() -> Pubkey {
+    Pubkey::find_program_address(&[b"config"], &solanaform::ID).0
+}
+
+pub fn form_schema_address(form: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"schema", form.as_ref()], &solanaform::ID).0
+}
+
+pub fn participant_address(form: &Pubkey, wallet: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"participant", form.as_ref(), wallet.as_ref()],
+        &solanaform::ID,
+    )
+    .0
+}
+
+pub fn response_address(form: &Pubkey, wallet: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"response", form.as_ref(), wallet.as_ref()],
+        &solanaform::ID,
+    )
+    .0
+}
+
+/// submit_response instruction with `answers` encrypted to the form's response key
+pub fn submit_encrypted_response(
+    form: &Pubkey,
+    wallet: &Pubkey,
+    recipient_key: &[u8; 32],
+    answers: &[Answer],
+) -> Result<Instruction, ClientError> {
+    let envelope = encrypt_answers(recipient_key, form, wallet, answers)?;
+    let accounts = solanaform::accounts::SubmitResponse {
+        form: *form,
+        config: config_address(),
+        form_schema: form_schema_address(form),
+        participant: participant_address(form, wallet),
+        response: response_address(form, wallet),
+        wallet: *wallet,
+        system_program: system_program::ID,
+    };
+
+    Ok(Instruction {
+        program_id: solanaform::ID,
+        accounts: accounts.to_account_metas(None),
+        data: solanaform::instruction::SubmitResponse {
+            payload: ResponsePayload::Encrypted(envelope),
+        }
+        .data(),
+    })
+}
+
+/// update_response instruction with `answers` encrypted to the form's response key
+pub fn update_encrypted_response(
+    form: &Pubkey,
+    wallet: &Pubkey,
+    recipient_key: &[u8; 32],
+    answers: &[Answer],
+) -> Result<Instruction, ClientError> {
+    let envelope = encrypt_answers(recipient_key, form, wallet, answers)?;
+    let accounts = solanaform::accounts::UpdateResponse {
+        form: *form,
+        config: config_address(),
+        form_schema: form_schema_address(form),
+        response: response_address(form, wallet),
+        wallet: *wallet,
+        system_program: system_program::ID,
+    };
+
+    Ok(Instruction {
+        program_id: solanaform::ID,
+        accounts: accounts.to_account_metas(None),
+        data: solanaform::instruction::UpdateResponse {
+            payload: ResponsePayload::Encrypted(envelope),
+        }
+        .data(),
+    })
+}
+
+// ============ EXPORT ============
+
+/// One row of a creator's export
+#[derive(Debug)]
+pub struct ExportedResponse {
+    pub wallet: Pubkey,
+    pub schema_version: u16,
+    pub answers: Result<Vec<Answer>, ClientError>,
+}
+
+/// Decrypt fetched Response accounts, plaintext responses are passed through
+/// A response that fails to decrypt is reported per row instead of failing the export
+pub fn export_responses(
+    secret_key: &StaticSecret,
+    responses: &[Response],
+) -> Vec<ExportedResponse> {
+    responses
+        .iter()
+        .map(|response| ExportedResponse {
+            wallet: response.wallet,
+            schema_version: response.schema_version,
+            answers: match &response.payload {
+                ResponsePayload::Answers(answers) => Ok(answers.clone()),
+                ResponsePayload::Encrypted(envelope) => {
+                    decrypt_answers(secret_key, &response.form, &response.wallet, envelope)
+                }
+            },
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solanaform::envelope::validate_envelope;
+
+    fn answers() -> Vec<Answer> {
+        vec![
+            Answer::Choice(2),
+            Answer::Integer(-7),
+            Answer::ShortText("gm".to_string()),
+            Answer::Skipped,
+        ]
+    }
+
+    #[test]
+    fn encrypted_answers_round_trip() {
+        let (secret, public) = generate_response_key();
+        let form = Pubkey::new_unique();
+        let wallet = Pubkey::new_unique();
+
+        let envelope = encrypt_answers(&public, &form, &wallet, &answers()).unwrap();
+        assert!(validate_envelope(&envelope).is_ok());
+        assert_eq!(
+            decrypt_answers(&secret, &form, &wallet, &envelope).unwrap(),
+            answers()
+        );
+    }
+
+    #[test]
+    fn decryption_is_bound_to_form_and_wallet() {
+        let (secret, public) = generate_response_key();
+        let form = Pubkey::new_unique();
+        let wallet = Pubkey::new_unique();
+        let envelope = encrypt_answers(&public, &form, &wallet, &answers()).unwrap();
+
+        let other = Pubkey::new_unique();
+        assert!(matches!(
+            decrypt_answers(&secret, &other, &wallet, &envelope),
+            Err(ClientError::DecryptionFailed)
+        ));
+        assert!(matches!(
+            decrypt_answers(&secret, &form, &other, &envelope),
+            Err(ClientError::DecryptionFailed)
+        ));
+    }
+
+    #[test]
+    fn decryption_fails_with_another_key() {
+        let (_, public) = generate_response_key();
+        let (other_secret, _) = generate_response_key();
+        let form = Pubkey::new_unique();
+        let wallet = Pubkey::new_unique();
+        let envelope = encrypt_answers(&public, &form, &wallet, &answers()).unwrap();
+
+        assert!(matches!(
+            decrypt_answers(&other_secret, &form, &wallet, &envelope),
+            Err(ClientError::DecryptionFailed)
+        ));
+    }
+}
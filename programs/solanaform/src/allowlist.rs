@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+// ============ MERKLE ALLOWLIST ============
+// Leaves are hash(prefix || wallet || allocation), nodes hash their children in sorted
+// order so proofs don't need left/right flags. Leaf and node prefixes differ so a node
+// can never be passed off as a leaf.
+
+const LEAF_PREFIX: &[u8] = b"solana_form:allowlist:leaf";
+const NODE_PREFIX: &[u8] = b"solana_form:allowlist:node";
+
+/// Leaf for `wallet`, pass allocation 0 for lists without per-wallet allocations
+pub fn allowlist_leaf(wallet: &Pubkey, allocation: u64) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, wallet.as_ref(), &allocation.to_le_bytes()]).to_bytes()
+}
+
+/// Whether `proof` links `leaf` to `root`
+pub fn verify_allowlist_proof(root: &[u8; 32], leaf: [u8; 32], proof: &[[u8; 32]]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        let (left, right) = if node <= *sibling {
+            (node, *sibling)
+        } else {
+            (*sibling, node)
+        };
+        hashv(&[NODE_PREFIX, &left, &right]).to_bytes()
+    });
+    computed == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        let (left, right) = if a <= b { (a, b) } else { (b, a) };
+        hashv(&[NODE_PREFIX, &left, &right]).to_bytes()
+    }
+
+    struct Tree {
+        wallets: Vec<Pubkey>,
+        leaves: Vec<[u8; 32]>,
+        inner: [[u8; 32]; 2],
+        root: [u8; 32],
+    }
+
+    /// Four wallets with allocations 1..=4
+    fn tree() -> Tree {
+        let wallets: Vec<Pubkey> = (1..=4u8).map(|i| Pubkey::new_from_array([i; 32])).collect();
+        let leaves: Vec<[u8; 32]> = wallets
+            .iter()
+            .zip(1..=4u64)
+            .map(|(wallet, allocation)| allowlist_leaf(wallet, allocation))
+            .collect();
+        let inner = [node(leaves[0], leaves[1]), node(leaves[2], leaves[3])];
+        let root = node(inner[0], inner[1]);
+        Tree {
+            wallets,
+            leaves,
+            inner,
+            root,
+        }
+    }
+
+    #[test]
+    fn valid_proofs_pass() {
+        let Tree {
+            wallets,
+            leaves,
+            inner,
+            root,
+        } = tree();
+        let proofs = [
+            [leaves[1], inner[1]],
+            [leaves[0], inner[1]],
+            [leaves[3], inner[0]],
+            [leaves[2], inner[0]],
+        ];
+        for (i, proof) in proofs.iter().enumerate() {
+            let leaf = allowlist_leaf(&wallets[i], i as u64 + 1);
+            assert!(verify_allowlist_proof(&root, leaf, proof));
+        }
+    }
+
+    #[test]
+    fn wrong_allocation_fails() {
+        let Tree {
+            wallets,
+            leaves,
+            inner,
+            root,
+        } = tree();
+        let leaf = allowlist_leaf(&wallets[0], 2);
+        assert!(!verify_allowlist_proof(&root, leaf, &[leaves[1], inner[1]]));
+    }
+
+    #[test]
+    fn wrong_wallet_fails() {
+        let Tree {
+            leaves,
+            inner,
+            root,
+            ..
+        } = tree();
+        let leaf = allowlist_leaf(&Pubkey::new_from_array([9; 32]), 1);
+        assert!(!verify_allowlist_proof(&root, leaf, &[leaves[1], inner[1]]));
+    }
+
+    #[test]
+    fn node_passed_as_leaf_fails() {
+        let Tree { inner, root, .. } = tree();
+        // A wallet whose key is an inner node hashes to a leaf, not to that node
+        let leaf = allowlist_leaf(&Pubkey::new_from_array(inner[0]), 0);
+        assert_ne!(leaf, inner[0]);
+        assert!(!verify_allowlist_proof(&root, leaf, &[inner[1]]));
+    }
+
+    #[test]
+    fn empty_proof_only_matches_the_root() {
+        let Tree { wallets, root, .. } = tree();
+        let single = allowlist_leaf(&wallets[0], 1);
+        assert!(!verify_allowlist_proof(&root, single, &[]));
+        assert!(verify_allowlist_proof(&single, single, &[]));
+    }
+}
@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use solana_instructions_sysvar::{load_current_index_checked, load_instruction_at_checked};
+use solana_sdk_ids::ed25519_program;
+
+use crate::errors::ErrorCode;
+
+// ============ EMAIL ATTESTATION ============
+// The verifier backend signs (form || wallet || email_hash || expiry) with Ed25519.
+// The client puts the Ed25519 program instruction right before submit_form, the
+// runtime checks the signature and we check that it signs the expected message.
+
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_LEN: usize = 14;
+const PUBKEY_LEN: usize = 32;
+
+/// Message the verifier signs for one submission
+pub fn attestation_message(
+    form: &Pubkey,
+    wallet: &Pubkey,
+    email_hash: &[u8; 32],
+    expiry: i64,
+) -> Vec<u8> {
+    [
+        form.as_ref(),
+        wallet.as_ref(),
+        email_hash.as_ref(),
+        &expiry.to_le_bytes(),
+    ]
+    .concat()
+}
+
+/// Fails unless the instruction before the current one is an Ed25519 signature
+/// check of `message` by `verifier`
+pub fn verify_attestation(
+    instructions_sysvar: &AccountInfo,
+    verifier: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)? as usize;
+    require!(current_index > 0, ErrorCode::MissingEmailAttestation);
+
+    let ed25519_ix = load_instruction_at_checked(current_index - 1, instructions_sysvar)?;
+    require!(
+        ed25519_ix.program_id == ed25519_program::ID,
+        ErrorCode::MissingEmailAttestation
+    );
+
+    let (signer, signed_message) = parse_ed25519_instruction(&ed25519_ix.data)
+        .ok_or(error!(ErrorCode::InvalidEmailAttestation))?;
+    require!(
+        signer == verifier.as_ref() && signed_message == message,
+        ErrorCode::InvalidEmailAttestation
+    );
+    Ok(())
+}
+
+/// (public key, message) of a single-signature Ed25519 instruction whose
+/// offsets all point into its own data
+fn parse_ed25519_instruction(data: &[u8]) -> Option<(&[u8], &[u8])> {
+    // u8 signature count, u8 padding, then one offsets struct per signature
+    if data.first() != Some(&1) {
+        return None;
+    }
+    let offsets =
+        data.get(SIGNATURE_OFFSETS_START..SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_LEN)?;
+    let read_u16 = |i: usize| u16::from_le_bytes([offsets[i], offsets[i + 1]]);
+
+    let signature_ix_index = read_u16(2);
+    let public_key_offset = read_u16(4) as usize;
+    let public_key_ix_index = read_u16(6);
+    let message_offset = read_u16(8) as usize;
+    let message_size = read_u16(10) as usize;
+    let message_ix_index = read_u16(12);
+
+    // u16::MAX means "this instruction", anything else could point at data we never see
+    if [signature_ix_index, public_key_ix_index, message_ix_index]
+        .iter()
+        .any(|index| *index != u16::MAX)
+    {
+        return None;
+    }
+
+    let public_key = data.get(public_key_offset..public_key_offset + PUBKEY_LEN)?;
+    let message = data.get(message_offset..message_offset + message_size)?;
+    Some((public_key, message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DATA_START: usize = SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_LEN;
+    const SIGNATURE_LEN: usize = 64;
+
+    /// Ed25519 program data as built by the client: header, offsets, key, signature, message
+    fn ed25519_data(public_key: &[u8; 32], message: &[u8], ix_indices: [u16; 3]) -> Vec<u8> {
+        let public_key_offset = DATA_START;
+        let signature_offset = public_key_offset + PUBKEY_LEN;
+        let message_offset = signature_offset + SIGNATURE_LEN;
+
+        let mut data = vec![1u8, 0];
+        for field in [
+            signature_offset as u16,
+            ix_indices[0],
+            public_key_offset as u16,
+            ix_indices[1],
+            message_offset as u16,
+            message.len() as u16,
+            ix_indices[2],
+        ] {
+            data.extend_from_slice(&field.to_le_bytes());
+        }
+        data.extend_from_slice(public_key);
+        data.extend_from_slice(&[0u8; SIGNATURE_LEN]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    fn message() -> Vec<u8> {
+        attestation_message(
+            &Pubkey::new_from_array([1; 32]),
+            &Pubkey::new_from_array([2; 32]),
+            &[3; 32],
+            1_700_000_000,
+        )
+    }
+
+    #[test]
+    fn parses_single_signature_instruction() {
+        let message = message();
+        let data = ed25519_data(&[7; 32], &message, [u16::MAX; 3]);
+        let (public_key, signed_message) = parse_ed25519_instruction(&data).unwrap();
+        assert_eq!(public_key, [7; 32].as_slice());
+        assert_eq!(signed_message, message.as_slice());
+    }
+
+    #[test]
+    fn rejects_other_signature_counts() {
+        let mut data = ed25519_data(&[7; 32], &message(), [u16::MAX; 3]);
+        for count in [0u8, 2] {
+            data[0] = count;
+            assert!(parse_ed25519_instruction(&data).is_none());
+        }
+        assert!(parse_ed25519_instruction(&[]).is_none());
+    }
+
+    #[test]
+    fn rejects_offsets_into_other_instructions() {
+        for field in 0..3 {
+            let mut ix_indices = [u16::MAX; 3];
+            ix_indices[field] = 0;
+            let data = ed25519_data(&[7; 32], &message(), ix_indices);
+            assert!(parse_ed25519_instruction(&data).is_none());
+        }
+    }
+
+    #[test]
+    fn rejects_out_of_range_offsets() {
+        let message = message();
+        let data = ed25519_data(&[7; 32], &message, [u16::MAX; 3]);
+
+        // Truncated message, and a public key offset past the end
+        assert!(parse_ed25519_instruction(&data[..data.len() - 1]).is_none());
+        let mut past_end = data.clone();
+        let public_key_offset = SIGNATURE_OFFSETS_START + 4;
+        past_end[public_key_offset..public_key_offset + 2]
+            .copy_from_slice(&(data.len() as u16 - 16).to_le_bytes());
+        assert!(parse_ed25519_instruction(&past_end).is_none());
+
+        // Header without the offsets struct
+        assert!(parse_ed25519_instruction(&data[..DATA_START - 1]).is_none());
+    }
+}
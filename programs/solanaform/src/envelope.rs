@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+
+// ============ ENCRYPTED RESPONSE ENVELOPE ============
+// version (1) || ephemeral X25519 public key (32) || nonce (24) || ciphertext + tag (16)
+// The ciphertext is XChaCha20-Poly1305 over the Borsh-encoded answers, keyed from the
+// Diffie-Hellman of the ephemeral key and Form.response_encryption_key. The program
+// cannot decrypt, it only checks the envelope is well formed. See the solanaform-client crate.
+
+pub const ENVELOPE_VERSION: u8 = 1;
+pub const EPHEMERAL_KEY_LEN: usize = 32;
+pub const NONCE_LEN: usize = 24;
+pub const TAG_LEN: usize = 16;
+pub const ENVELOPE_HEADER_LEN: usize = 1 + EPHEMERAL_KEY_LEN + NONCE_LEN;
+
+pub fn validate_envelope(envelope: &[u8]) -> Result<()> {
+    require!(
+        envelope.len() >= ENVELOPE_HEADER_LEN + TAG_LEN,
+        ErrorCode::InvalidEnvelope
+    );
+    require!(
+        envelope[0] == ENVELOPE_VERSION,
+        ErrorCode::InvalidEnvelope
+    );
+    // An all-zero ephemeral key is a low-order point, the shared secret would be zero
+    require!(
+        envelope[1..1 + EPHEMERAL_KEY_LEN].iter().any(|byte| *byte != 0),
+        ErrorCode::InvalidEnvelope
+    );
+    Ok(())
+}
@@ -0,0 +1,265 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Form is not active")]
+    FormInactive,
+
+    #[msg("Prizes already distributed")]
+    AlreadyDistributed,
+
+    #[msg("Prize pool already filled")]
+    PrizePoolFilled,
+
+    #[msg("Deadline has passed")]
+    DeadlinePassed,
+
+    #[msg("Deadline has not been reached yet")]
+    DeadlineNotReached,
+
+    #[msg("Maximum participants reached")]
+    MaxParticipantsReached,
+
+    #[msg("Unauthorized")]
+    Unauthorized,
+
+    #[msg("No participants to distribute to")]
+    NoParticipants,
+
+    #[msg("Prizes not distributed yet")]
+    NotDistributed,
+
+    #[msg("Prize already claimed")]
+    AlreadyClaimed,
+
+    #[msg("Cannot close form with active participants")]
+    CannotClose,
+
+    #[msg("Switchboard account parsing failed")]
+    SwitchboardError,
+
+    #[msg("Randomness not yet resolved")]
+    RandomnessNotResolved,
+
+    #[msg("Participant is not a winner")]
+    NotAWinner,
+
+    #[msg("Randomness already requested")]
+    RandomnessAlreadyRequested,
+
+    #[msg("Randomness not yet requested")]
+    RandomnessNotRequested,
+
+    #[msg("Randomness not settled yet")]
+    RandomnessNotSettled,
+
+    #[msg("Too early for emergency fallback (wait 7 days after request)")]
+    TooEarlyForFallback,
+
+    #[msg("Instruction not available for this form's randomness backend")]
+    WrongRandomnessBackend,
+
+    #[msg("Revealed secret does not match the commitment")]
+    InvalidSeedReveal,
+
+    #[msg("Reveal slot has not been reached yet")]
+    RevealSlotNotReached,
+
+    #[msg("Slot hash for the reveal slot is not available yet or no longer available")]
+    SlotHashUnavailable,
+
+    #[msg("Too early to forfeit the reveal")]
+    TooEarlyForForfeit,
+
+    #[msg("Winner already declared")]
+    AlreadyDeclaredWinner,
+
+    #[msg("Randomness backend is not enabled in this program build")]
+    RandomnessBackendDisabled,
+
+    #[msg("Seed commitment is required for commit-reveal forms only")]
+    InvalidSeedCommitment,
+
+    #[msg("Token accounts are required for token prize forms")]
+    MissingTokenAccounts,
+
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+
+    #[msg("Prize tiers must be empty for equal split, or one per winner summing to 10000 bps / the prize pool")]
+    InvalidPrizeSchedule,
+
+    #[msg("Winner count must be between 1 and the max winners, and at most max participants")]
+    InvalidWinnerCount,
+
+    #[msg("Prize pool has not been fully deposited yet")]
+    FormNotFunded,
+
+    #[msg("Form is still accepting submissions")]
+    FormStillOpen,
+
+    #[msg("Form has been cancelled")]
+    FormCancelled,
+
+    #[msg("Form has been finalized")]
+    FormFinalized,
+
+    #[msg("Form has not been cancelled")]
+    FormNotCancelled,
+
+    #[msg("Cannot close form while winners can still claim their prizes")]
+    UnclaimedPrizes,
+
+    #[msg("Claim window has closed")]
+    ClaimWindowClosed,
+
+    #[msg("Claim window is still open")]
+    ClaimWindowOpen,
+
+    #[msg("Sponsorship would raise the pool above the prize pool")]
+    SponsorshipExceedsPool,
+
+    #[msg("Sponsorship amount must be greater than zero")]
+    InvalidSponsorshipAmount,
+
+    #[msg("Sponsors have not been refunded yet")]
+    UnrefundedSponsors,
+
+    #[msg("Creator fee must be at most 10000 bps")]
+    InvalidCreatorFee,
+
+    #[msg("Creator fees can only be withdrawn once randomness is requested")]
+    CreatorFeesLocked,
+
+    #[msg("Wallet is not on the form's allowlist")]
+    NotAllowlisted,
+
+    #[msg("Email attestation instruction is missing")]
+    MissingEmailAttestation,
+
+    #[msg("Email attestation does not match the verifier, form, wallet or email")]
+    InvalidEmailAttestation,
+
+    #[msg("Email attestation has expired")]
+    EmailAttestationExpired,
+
+    #[msg("Email claims can only be closed once submissions are closed")]
+    SubmissionsStillOpen,
+
+    #[msg("Close every email claim before closing the form")]
+    OpenEmailClaims,
+
+    #[msg("Responses were given for a different schema version")]
+    SchemaVersionMismatch,
+
+    #[msg("Schema needs 1 to 32 valid questions and a response cap of at most 10240 bytes")]
+    InvalidSchema,
+
+    #[msg("Schema can only be created before submissions open")]
+    SchemaLocked,
+
+    #[msg("Answers do not match the form schema")]
+    InvalidAnswers,
+
+    #[msg("Response exceeds the form's size cap")]
+    ResponseTooLarge,
+
+    #[msg("Encrypted forms only take encrypted responses, and plaintext forms plaintext ones")]
+    WrongResponseEncryption,
+
+    #[msg("Encrypted response envelope is malformed")]
+    InvalidEnvelope,
+
+    #[msg("Form is not a quiz")]
+    NotAQuiz,
+
+    #[msg("Answer key has not been revealed yet")]
+    AnswerKeyNotRevealed,
+
+    #[msg("Answer key already revealed")]
+    AnswerKeyAlreadyRevealed,
+
+    #[msg("Answer key does not match the commitment")]
+    InvalidAnswerKey,
+
+    #[msg("Participants can still prove eligibility")]
+    EligibilityWindowOpen,
+
+    #[msg("Eligibility window has closed")]
+    EligibilityWindowClosed,
+
+    #[msg("Answers do not match the participant's commitment")]
+    InvalidQuizAnswers,
+
+    #[msg("Answers do not match the answer key")]
+    IncorrectAnswers,
+
+    #[msg("Participant already marked eligible")]
+    AlreadyEligible,
+
+    #[msg("Participant is not eligible to win")]
+    NotEligible,
+
+    #[msg("Skill-ranked forms must be quizzes and cannot use the admin-declared backend")]
+    InvalidWinnerSelection,
+
+    #[msg("Instruction is only available for skill-ranked forms")]
+    RankedFormOnly,
+
+    #[msg("Instruction is not available for skill-ranked forms")]
+    LotteryFormOnly,
+
+    #[msg("Leaderboard account is required for skill-ranked forms")]
+    MissingLeaderboard,
+
+    #[msg("Too many participants tied for the last prize")]
+    LeaderboardFull,
+
+    #[msg("Last participant account is missing or does not hold the last index")]
+    InvalidLastParticipant,
+
+    #[msg("Rent reserve cannot cover this relayed submission")]
+    RentReserveExhausted,
+
+    #[msg("Gate token account (and metadata for collections) is required for this form")]
+    MissingGateAccounts,
+
+    #[msg("Wallet does not hold the token or NFT required by this form")]
+    ParticipationGateNotMet,
+
+    #[msg("Signer is not the pending authority")]
+    NotPendingAuthority,
+
+    #[msg("Operator permissions contain unknown bits")]
+    InvalidPermissions,
+
+    #[msg("Form already has the maximum number of operators")]
+    TooManyOperators,
+
+    #[msg("New deadline must be later than the current one")]
+    InvalidDeadline,
+
+    #[msg("Program is paused")]
+    ProgramPaused,
+
+    #[msg("Config needs a fee of at most 10000 bps, 1 to 10 max winners and a non-negative max duration")]
+    InvalidConfig,
+
+    #[msg("Form exceeds the program's prize pool, winner or duration limits")]
+    ExceedsProtocolLimits,
+
+    #[msg("Treasury does not hold enough to withdraw this amount")]
+    InsufficientTreasury,
+
+    #[msg("Responses can only be closed once the form is settled or cancelled")]
+    ResponsesLocked,
+
+    #[msg("Close every response before closing the form")]
+    OpenResponses,
+
+    #[msg("Randomness account must be committed in the previous slot")]
+    StaleRandomness,
+
+    #[msg("Form cannot be cancelled once the answer key is revealed")]
+    CannotCancelAfterReveal,
+}
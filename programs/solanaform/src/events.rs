@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+use crate::state::FormStatus;
+
+// ============ EVENTS ============
+
+#[event]
+pub struct FormStatusChanged {
+    pub form: Pubkey,
+    pub from: FormStatus,
+    pub to: FormStatus,
+    pub timestamp: i64,
+}
@@ -0,0 +1,207 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::errors::ErrorCode;
+use crate::state::ParticipationGate;
+
+// ============ PARTICIPATION GATES ============
+// Token gates check a token account owned by the submitter. Collection gates also
+// check the Metaplex metadata of the NFT held in it for a verified collection.
+
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey =
+    pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// Fails unless the submitter's token account (and NFT metadata) satisfies `gate`
+/// The token account's owner is checked by the SubmitForm constraints
+pub fn check_participation_gate(
+    gate: &ParticipationGate,
+    token_account: Option<&InterfaceAccount<TokenAccount>>,
+    metadata: Option<&AccountInfo>,
+) -> Result<()> {
+    match gate {
+        ParticipationGate::None => Ok(()),
+        ParticipationGate::TokenBalance { mint, min_amount } => {
+            let token_account = token_account.ok_or(error!(ErrorCode::MissingGateAccounts))?;
+            require!(
+                token_account.mint == *mint && token_account.amount >= *min_amount,
+                ErrorCode::ParticipationGateNotMet
+            );
+            Ok(())
+        }
+        ParticipationGate::NftCollection { collection } => {
+            let (Some(token_account), Some(metadata)) = (token_account, metadata) else {
+                return err!(ErrorCode::MissingGateAccounts);
+            };
+            require!(
+                token_account.amount >= 1,
+                ErrorCode::ParticipationGateNotMet
+            );
+
+            let (metadata_address, _) = Pubkey::find_program_address(
+                &[
+                    b"metadata",
+                    TOKEN_METADATA_PROGRAM_ID.as_ref(),
+                    token_account.mint.as_ref(),
+                ],
+                &TOKEN_METADATA_PROGRAM_ID,
+            );
+            require!(
+                metadata.key() == metadata_address && *metadata.owner == TOKEN_METADATA_PROGRAM_ID,
+                ErrorCode::ParticipationGateNotMet
+            );
+
+            let verified_collection = verified_collection(&metadata.try_borrow_data()?);
+            require!(
+                verified_collection == Some(*collection),
+                ErrorCode::ParticipationGateNotMet
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Verified collection key of a Metaplex metadata account, parsed manually to avoid
+/// depending on the metadata crate. Layout: key, update authority, mint, name, symbol,
+/// uri, seller fee, creators, primary sale, mutable, edition nonce, token standard, collection
+fn verified_collection(data: &[u8]) -> Option<Pubkey> {
+    let mut reader = MetadataReader { data, offset: 0 };
+    reader.skip(1 + 32 + 32)?;
+    for _ in 0..3 {
+        let len = reader.read_u32()? as usize;
+        reader.skip(len)?;
+    }
+    reader.skip(2)?;
+    if reader.read_u8()? == 1 {
+        let creators = reader.read_u32()? as usize;
+        reader.skip(creators.checked_mul(32 + 1 + 1)?)?;
+    }
+    reader.skip(2)?;
+    for _ in 0..2 {
+        if reader.read_u8()? == 1 {
+            reader.skip(1)?;
+        }
+    }
+    if reader.read_u8()? != 1 {
+        return None;
+    }
+    let verified = reader.read_u8()? == 1;
+    let key = Pubkey::try_from(reader.take(32)?).ok()?;
+    verified.then_some(key)
+}
+
+struct MetadataReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> MetadataReader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let bytes = self.data.get(self.offset..self.offset.checked_add(len)?)?;
+        self.offset += len;
+        Some(bytes)
+    }
+
+    fn skip(&mut self, len: usize) -> Option<()> {
+        self.take(len).map(|_| ())
+    }
+
+    fn read_u8(&mut self) -> Option<u8> {
+        self.take(1).map(|bytes| bytes[0])
+    }
+
+    fn read_u32(&mut self) -> Option<u32> {
+        self.take(4)
+            .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COLLECTION: [u8; 32] = [9; 32];
+
+    fn borsh_string(data: &mut Vec<u8>, value: &str) {
+        data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        data.extend_from_slice(value.as_bytes());
+    }
+
+    /// Metaplex Metadata account data up to the collection field
+    fn metadata(creators: usize, collection: Option<(bool, [u8; 32])>) -> Vec<u8> {
+        let mut data = vec![4u8]; // Key::MetadataV1
+        data.extend_from_slice(&[1; 32]); // update authority
+        data.extend_from_slice(&[2; 32]); // mint
+        borsh_string(&mut data, "Gated Pass #1");
+        borsh_string(&mut data, "PASS");
+        borsh_string(&mut data, "https://example.com/1.json");
+        data.extend_from_slice(&500u16.to_le_bytes()); // seller fee
+        if creators == 0 {
+            data.push(0);
+        } else {
+            data.push(1);
+            data.extend_from_slice(&(creators as u32).to_le_bytes());
+            for i in 0..creators {
+                data.extend_from_slice(&[10 + i as u8; 32]);
+                data.extend_from_slice(&[1, 100 / creators as u8]);
+            }
+        }
+        data.extend_from_slice(&[1, 1]); // primary sale, mutable
+        data.extend_from_slice(&[1, 255]); // edition nonce
+        data.extend_from_slice(&[1, 0]); // token standard
+        match collection {
+            Some((verified, key)) => {
+                data.extend_from_slice(&[1, verified as u8]);
+                data.extend_from_slice(&key);
+            }
+            None => data.push(0),
+        }
+        data.push(0); // uses, ignored
+        data
+    }
+
+    #[test]
+    fn reads_verified_collection_with_creators() {
+        let data = metadata(3, Some((true, COLLECTION)));
+        assert_eq!(
+            verified_collection(&data),
+            Some(Pubkey::new_from_array(COLLECTION))
+        );
+    }
+
+    #[test]
+    fn reads_verified_collection_without_creators() {
+        let data = metadata(0, Some((true, COLLECTION)));
+        assert_eq!(
+            verified_collection(&data),
+            Some(Pubkey::new_from_array(COLLECTION))
+        );
+    }
+
+    #[test]
+    fn ignores_unverified_or_missing_collection() {
+        assert_eq!(
+            verified_collection(&metadata(1, Some((false, COLLECTION)))),
+            None
+        );
+        assert_eq!(verified_collection(&metadata(1, None)), None);
+    }
+
+    #[test]
+    fn rejects_truncated_data() {
+        let data = metadata(2, Some((true, COLLECTION)));
+        // Everything up to the last byte of the collection key
+        let collection_end = data.len() - 1;
+        for len in [0, 1, 40, 65, 70, collection_end - 32, collection_end - 1] {
+            assert_eq!(verified_collection(&data[..len]), None, "length {}", len);
+        }
+        assert!(verified_collection(&data[..collection_end]).is_some());
+    }
+
+    #[test]
+    fn rejects_oversized_lengths() {
+        let mut data = metadata(0, Some((true, COLLECTION)));
+        // Name length far past the end of the account
+        data[65..69].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(verified_collection(&data), None);
+    }
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Operators stay in place, the new authority can remove them with set_operator
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+    let new_authority = ctx.accounts.new_authority.key();
+
+    require!(
+        form.pending_authority != Pubkey::default() && form.pending_authority == new_authority,
+        ErrorCode::NotPendingAuthority
+    );
+
+    let previous_authority = form.authority;
+    form.authority = new_authority;
+    form.pending_authority = Pubkey::default();
+
+    msg!(
+        "Authority transferred from {} to {}",
+        previous_authority,
+        new_authority
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    pub new_authority: Signer<'info>,
+}
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::vault::transfer_from_vault;
+
+/// Not allowed once randomness is requested, the authority could otherwise
+/// cancel after seeing an unfavorable outcome. The same goes for quizzes once the
+/// answer key is revealed, eligibility and skill-ranked scores are known from then on.
+pub fn handler(ctx: Context<CancelForm>) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+
+    require!(
+        ctx.accounts.authority.key() == form.authority,
+        ErrorCode::Unauthorized
+    );
+    require!(
+        !form.answer_key_revealed,
+        ErrorCode::CannotCancelAfterReveal
+    );
+    transition(form, FormStatus::Cancelled)?;
+
+    // Sponsor contributions and entry fees stay in the pool until
+    // refund_sponsor / close_participant, the creator cut is given up
+    let fee_pool_share = form.entry_fees_collected - form.creator_fees;
+    let refund_amount = form.collected_amount - form.sponsored_amount - fee_pool_share;
+
+    if form.is_token_pool() {
+        let (Some(mint), Some(vault), Some(to), Some(token_program)) = (
+            ctx.accounts.prize_mint.as_ref(),
+            ctx.accounts.vault.as_ref(),
+            ctx.accounts.authority_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return err!(ErrorCode::MissingTokenAccounts);
+        };
+
+        if refund_amount > 0 {
+            transfer_from_vault(form, vault, mint, to, token_program, refund_amount)?;
+        }
+    } else {
+        // Transfer the pool from form PDA back to the authority, rent stays until close_form
+        **form.to_account_info().try_borrow_mut_lamports()? -= refund_amount;
+        **ctx.accounts.authority.try_borrow_mut_lamports()? += refund_amount;
+    }
+
+    form.collected_amount = form.sponsored_amount + form.entry_fees_collected;
+    form.creator_fees = 0;
+
+    msg!(
+        "Form cancelled, {} refunded to authority. Participants and sponsors can now be refunded",
+        refund_amount
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelForm<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump,
+        has_one = authority
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // Token prize forms only
+    #[account(address = form.prize_mint)]
+    pub prize_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", form.key().as_ref()],
+        bump
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = form.prize_mint,
+        token::authority = authority
+    )]
+    pub authority_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// FIX: Issue 1 - Authority Manipulation
+/// The winner set is derived on-chain at settlement, this is just a lookup
+pub fn handler(ctx: Context<CheckWinnerStatus>) -> Result<()> {
+    let form = &ctx.accounts.form;
+    let participant = &mut ctx.accounts.participant;
+
+    require!(
+        form.status == FormStatus::Settled,
+        ErrorCode::RandomnessNotSettled
+    );
+
+    let is_winner = form
+        .draw_index(participant)
+        .is_some_and(|index| form.winners.contains(&index));
+
+    participant.is_winner = is_winner;
+
+    if is_winner {
+        msg!("🎉 Participant #{} is a WINNER!", participant.participant_index);
+    } else {
+        msg!("Participant #{} did not win this time", participant.participant_index);
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CheckWinnerStatus<'info> {
+    #[account(
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump
+    )]
+    pub form: Account<'info, Form>,
+
+    // No config: like claim_prize this ignores the pause
+
+    #[account(
+        mut,
+        seeds = [b"participant", form.key().as_ref(), participant.wallet.as_ref()],
+        bump = participant.bump,
+        has_one = form
+    )]
+    pub participant: Account<'info, Participant>,
+
+    // NOTE: No signer required! Anyone can check any participant's status
+}
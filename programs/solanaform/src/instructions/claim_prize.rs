@@ -0,0 +1,153 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::vault::transfer_from_vault;
+
+/// Only for participants in the winner set, or for every participant of a forfeited
+/// commit-reveal form. Does not need check_winner_status to run first.
+pub fn handler(ctx: Context<ClaimPrize>) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+    let participant = &mut ctx.accounts.participant;
+    let clock = Clock::get()?;
+
+    require!(form.status == FormStatus::Settled, ErrorCode::NotDistributed);
+    require!(
+        clock.unix_timestamp <= form.claim_deadline,
+        ErrorCode::ClaimWindowClosed
+    );
+    require!(!participant.claimed, ErrorCode::AlreadyClaimed);
+
+    // Pay the tier of the claimant's rank in the winner set,
+    // or split across everyone if the authority forfeited the reveal
+    let prize_amount = if form.reveal_forfeited {
+        form.collected_amount / form.participant_count as u64
+    } else {
+        let draw_index = form.draw_index(participant);
+        let rank = form
+            .winners
+            .iter()
+            .position(|index| Some(*index) == draw_index)
+            .ok_or(error!(ErrorCode::NotAWinner))?;
+        form.prize_schedule.prize_for_rank(
+            rank,
+            form.winners.len(),
+            form.collected_amount,
+            form.prize_pool,
+        )?
+    };
+
+    // Protocol fee at the rate snapshotted when the form was created
+    let (payout, protocol_fee) = split_protocol_fee(prize_amount, form.protocol_fee_bps);
+
+    if form.is_token_pool() {
+        let (Some(mint), Some(vault), Some(to), Some(token_program)) = (
+            ctx.accounts.prize_mint.as_ref(),
+            ctx.accounts.vault.as_ref(),
+            ctx.accounts.winner_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return err!(ErrorCode::MissingTokenAccounts);
+        };
+
+        // Transfer from vault to winner, signed by the form PDA
+        transfer_from_vault(form, vault, mint, to, token_program, payout)?;
+        if protocol_fee > 0 {
+            let treasury_token_account = ctx
+                .accounts
+                .treasury_token_account
+                .as_ref()
+                .ok_or(error!(ErrorCode::MissingTokenAccounts))?;
+            transfer_from_vault(
+                form,
+                vault,
+                mint,
+                treasury_token_account,
+                token_program,
+                protocol_fee,
+            )?;
+        }
+    } else {
+        // Transfer from form PDA to winner and treasury
+        **form.to_account_info().try_borrow_mut_lamports()? -= prize_amount;
+        **ctx.accounts.winner.try_borrow_mut_lamports()? += payout;
+        **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? += protocol_fee;
+    }
+
+    participant.claimed = true;
+    form.claimed_count += 1;
+    form.claimed_amount += prize_amount;
+
+    msg!(
+        "Prize claimed: {} lamports to {}, protocol fee: {}",
+        payout,
+        ctx.accounts.winner.key(),
+        protocol_fee
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimPrize<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump
+    )]
+    pub form: Account<'info, Form>,
+
+    // No config: claims ignore the pause, the claim window keeps running while paused
+
+    #[account(
+        mut,
+        seeds = [b"participant", form.key().as_ref(), winner.key().as_ref()],
+        bump = participant.bump,
+        has_one = form
+    )]
+    pub participant: Account<'info, Participant>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// CHECK: Winner receiving prize (must match participant.wallet)
+    #[account(
+        mut,
+        constraint = winner.key() == participant.wallet
+    )]
+    pub winner: AccountInfo<'info>,
+
+    // Token prize forms only
+    #[account(address = form.prize_mint)]
+    pub prize_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", form.key().as_ref()],
+        bump
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = form.prize_mint,
+        token::authority = winner
+    )]
+    pub winner_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    // Any token account of the treasury, required when the form charges a protocol fee
+    #[account(
+        mut,
+        token::mint = form.prize_mint,
+        token::authority = treasury
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// ANYONE can call this, uniqueness only matters while submissions are open
+pub fn handler(ctx: Context<CloseEmailClaim>, _email_hash: [u8; 32]) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+
+    require!(
+        !matches!(
+            form.status,
+            FormStatus::Draft | FormStatus::Funded | FormStatus::Open
+        ),
+        ErrorCode::SubmissionsStillOpen
+    );
+
+    form.email_claim_count -= 1;
+
+    // Rent goes back to the wallet, or to the rent reserve if a relayer paid it
+    let rent_sponsored = ctx.accounts.email_claim.rent_sponsored;
+    close_to_rent_payer(
+        &ctx.accounts.email_claim,
+        form,
+        ctx.accounts.wallet.to_account_info(),
+        rent_sponsored,
+    )?;
+
+    msg!("Email claim closed: {}", ctx.accounts.wallet.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(email_hash: [u8; 32])]
+pub struct CloseEmailClaim<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"email", form.key().as_ref(), email_hash.as_ref()],
+        bump = email_claim.bump,
+        has_one = form,
+        has_one = wallet
+    )]
+    pub email_claim: Account<'info, EmailClaim>,
+
+    /// CHECK: Wallet that paid the claim's rent (must match email_claim.wallet)
+    #[account(mut)]
+    pub wallet: AccountInfo<'info>,
+}
@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::vault::{close_vault, transfer_from_vault};
+
+pub fn handler(ctx: Context<CloseForm>) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+    let clock = Clock::get()?;
+
+    require!(
+        ctx.accounts.authority.key() == form.authority,
+        ErrorCode::Unauthorized
+    );
+    require!(
+        form.participant_count == 0
+            || matches!(form.status, FormStatus::Settled | FormStatus::Finalized),
+        ErrorCode::CannotClose
+    );
+    // Unclaimed prizes are protected until the claim window ends
+    if form.status == FormStatus::Settled {
+        require!(
+            form.all_prizes_claimed() || clock.unix_timestamp > form.claim_deadline,
+            ErrorCode::UnclaimedPrizes
+        );
+    }
+    require!(form.email_claim_count == 0, ErrorCode::OpenEmailClaims);
+    require!(form.response_count == 0, ErrorCode::OpenResponses);
+    // Sponsor deposits only go to the authority once they have been paid out as prizes,
+    // otherwise the form has to be cancelled and every sponsor refunded first
+    if !matches!(form.status, FormStatus::Settled | FormStatus::Finalized) {
+        require!(form.sponsored_amount == 0, ErrorCode::UnrefundedSponsors);
+    }
+    // Already finalized by sweep_unclaimed
+    if form.status != FormStatus::Finalized {
+        transition(form, FormStatus::Finalized)?;
+    }
+
+    if form.is_token_pool() {
+        let (Some(mint), Some(vault), Some(to), Some(token_program)) = (
+            ctx.accounts.prize_mint.as_ref(),
+            ctx.accounts.vault.as_ref(),
+            ctx.accounts.authority_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return err!(ErrorCode::MissingTokenAccounts);
+        };
+
+        // Refund remaining tokens, then the vault's rent
+        if vault.amount > 0 {
+            transfer_from_vault(form, vault, mint, to, token_program, vault.amount)?;
+        }
+        close_vault(
+            form,
+            vault,
+            ctx.accounts.authority.to_account_info(),
+            token_program,
+        )?;
+    }
+
+    // Remaining lamports will be refunded to authority via close constraint
+    msg!("Form closed and refunded");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseForm<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump,
+        has_one = authority,
+        close = authority
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // Forms with on-chain answers only, rent goes back to the authority
+    #[account(
+        mut,
+        seeds = [b"schema", form.key().as_ref()],
+        bump = form_schema.bump,
+        close = authority
+    )]
+    pub form_schema: Option<Account<'info, FormSchema>>,
+
+    // Skill-ranked forms only, rent goes back to the authority
+    #[account(
+        mut,
+        seeds = [b"leaderboard", form.key().as_ref()],
+        bump = leaderboard.bump,
+        close = authority
+    )]
+    pub leaderboard: Option<Account<'info, Leaderboard>>,
+
+    // Token prize forms only
+    #[account(address = form.prize_mint)]
+    pub prize_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", form.key().as_ref()],
+        bump
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = form.prize_mint,
+        token::authority = authority
+    )]
+    pub authority_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
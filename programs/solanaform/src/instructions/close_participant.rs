@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::vault::transfer_from_vault;
+
+/// ANYONE can call this, so the authority can close every participant
+/// of a cancelled form before closing the form itself
+pub fn handler(ctx: Context<CloseParticipant>) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+
+    require!(
+        form.status == FormStatus::Cancelled,
+        ErrorCode::FormNotCancelled
+    );
+
+    let refund_amount = ctx.accounts.participant.entry_fee_paid;
+    if refund_amount > 0 {
+        if form.is_token_pool() {
+            let (Some(mint), Some(vault), Some(to), Some(token_program)) = (
+                ctx.accounts.prize_mint.as_ref(),
+                ctx.accounts.vault.as_ref(),
+                ctx.accounts.wallet_token_account.as_ref(),
+                ctx.accounts.token_program.as_ref(),
+            ) else {
+                return err!(ErrorCode::MissingTokenAccounts);
+            };
+
+            transfer_from_vault(form, vault, mint, to, token_program, refund_amount)?;
+        } else {
+            **form.to_account_info().try_borrow_mut_lamports()? -= refund_amount;
+            **ctx.accounts.wallet.try_borrow_mut_lamports()? += refund_amount;
+        }
+
+        form.collected_amount -= refund_amount;
+        form.entry_fees_collected -= refund_amount;
+    }
+
+    form.participant_count -= 1;
+    // Response rent is refunded via close constraint
+    if ctx.accounts.response.is_some() {
+        form.response_count -= 1;
+    }
+
+    // Rent goes back to the wallet, or to the rent reserve if a relayer paid it
+    let rent_sponsored = ctx.accounts.participant.rent_sponsored;
+    close_to_rent_payer(
+        &ctx.accounts.participant,
+        form,
+        ctx.accounts.wallet.to_account_info(),
+        rent_sponsored,
+    )?;
+
+    msg!("Participant closed: {}", ctx.accounts.wallet.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseParticipant<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"participant", form.key().as_ref(), wallet.key().as_ref()],
+        bump = participant.bump,
+        has_one = form,
+        has_one = wallet
+    )]
+    pub participant: Account<'info, Participant>,
+
+    // Only if the participant stored answers with submit_response
+    #[account(
+        mut,
+        seeds = [b"response", form.key().as_ref(), wallet.key().as_ref()],
+        bump = response.bump,
+        has_one = form,
+        has_one = wallet,
+        close = wallet
+    )]
+    pub response: Option<Account<'info, Response>>,
+
+    /// CHECK: Participant wallet receiving the rent (must match participant.wallet)
+    #[account(mut)]
+    pub wallet: AccountInfo<'info>,
+
+    // Token prize forms with an entry fee only
+    #[account(address = form.prize_mint)]
+    pub prize_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", form.key().as_ref()],
+        bump
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = form.prize_mint,
+        token::authority = wallet
+    )]
+    pub wallet_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
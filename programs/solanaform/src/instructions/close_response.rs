@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// ANYONE can call this once the form is settled or cancelled,
+/// the creator exports the responses before settling the form
+pub fn handler(ctx: Context<CloseResponse>) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+
+    require!(
+        matches!(
+            form.status,
+            FormStatus::Settled | FormStatus::Finalized | FormStatus::Cancelled
+        ),
+        ErrorCode::ResponsesLocked
+    );
+
+    form.response_count -= 1;
+
+    // Rent is refunded to the wallet via close constraint
+    msg!("Response closed: {}", ctx.accounts.wallet.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseResponse<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"response", form.key().as_ref(), wallet.key().as_ref()],
+        bump = response.bump,
+        has_one = form,
+        has_one = wallet,
+        close = wallet
+    )]
+    pub response: Account<'info, Response>,
+
+    /// CHECK: Wallet that paid the response's rent (must match response.wallet)
+    #[account(mut)]
+    pub wallet: AccountInfo<'info>,
+}
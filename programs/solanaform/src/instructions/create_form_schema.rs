@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Usually sent in the same transaction as initialize_form
+pub fn handler(
+    ctx: Context<CreateFormSchema>,
+    questions: Vec<Question>,
+    max_response_size: u32,
+    response_encryption_key: Option<[u8; 32]>,
+) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+
+    require!(
+        form.is_authorized(&ctx.accounts.authority.key(), PERMISSION_EDIT_METADATA),
+        ErrorCode::Unauthorized
+    );
+    // Responses are sealed to this key, so only the creator can choose it
+    require!(
+        response_encryption_key.is_none() || ctx.accounts.authority.key() == form.authority,
+        ErrorCode::Unauthorized
+    );
+    require!(
+        matches!(form.status, FormStatus::Draft | FormStatus::Funded),
+        ErrorCode::SchemaLocked
+    );
+
+    let form_schema = &mut ctx.accounts.form_schema;
+    form_schema.form = form.key();
+    form_schema.schema_version = form.schema_version;
+    form_schema.max_response_size = max_response_size;
+    form_schema.questions = questions;
+    form_schema.bump = ctx.bumps.form_schema;
+    form_schema.validate()?;
+
+    form.response_encryption_key = response_encryption_key.unwrap_or_default();
+
+    msg!(
+        "Form schema created: {} questions, responses up to {} bytes, encrypted: {}",
+        form_schema.questions.len(),
+        max_response_size,
+        form.encrypts_responses()
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateFormSchema<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + FormSchema::INIT_SPACE,
+        seeds = [b"schema", form.key().as_ref()],
+        bump
+    )]
+    pub form_schema: Account<'info, FormSchema>,
+
+    /// Form authority, or an operator allowed to edit the form metadata
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Winners are pushed in declaration order, the form settles once
+/// min(draw pool, winner_count) winners have been declared
+pub fn handler(ctx: Context<DeclareWinner>) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+    let participant = &mut ctx.accounts.participant;
+    let clock = Clock::get()?;
+
+    require!(
+        form.randomness_backend == RandomnessBackend::AdminDeclared,
+        ErrorCode::WrongRandomnessBackend
+    );
+    require!(
+        clock.unix_timestamp >= form.deadline,
+        ErrorCode::DeadlineNotReached
+    );
+    require!(
+        ctx.accounts.authority.key() == form.authority,
+        ErrorCode::Unauthorized
+    );
+    require!(!participant.is_winner, ErrorCode::AlreadyDeclaredWinner);
+    form.require_draw_ready(clock.unix_timestamp)?;
+    let draw_index = form
+        .draw_index(participant)
+        .ok_or(error!(ErrorCode::NotEligible))?;
+
+    close_submissions(form)?;
+    form.check_transition(FormStatus::Settled)?;
+
+    participant.is_winner = true;
+    form.winners.push(draw_index);
+
+    if form.winners.len() as u32 == form.draw_pool_size().min(form.winner_count) {
+        transition(form, FormStatus::Settled)?;
+    }
+
+    msg!("Winner declared: {}", participant.wallet);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DeclareWinner<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump,
+        has_one = authority
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"participant", form.key().as_ref(), participant.wallet.as_ref()],
+        bump = participant.bump,
+        has_one = form
+    )]
+    pub participant: Account<'info, Participant>,
+
+    pub authority: Signer<'info>,
+}
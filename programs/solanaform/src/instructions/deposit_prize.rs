@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::vault::gross_amount_for_net;
+
+pub fn handler(ctx: Context<DepositPrize>) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+
+    form.check_transition(FormStatus::Funded)?;
+
+    let deposit_amount = form.prize_pool - form.collected_amount;
+
+    if !form.is_token_pool() {
+        // Transfer SOL from authority to form PDA
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: form.to_account_info(),
+                },
+            ),
+            deposit_amount,
+        )?;
+
+        form.collected_amount += deposit_amount;
+        msg!("Prize deposited: {} lamports", deposit_amount);
+    } else {
+        let (Some(mint), Some(from), Some(vault), Some(token_program)) = (
+            ctx.accounts.prize_mint.as_ref(),
+            ctx.accounts.authority_token_account.as_ref(),
+            ctx.accounts.vault.as_mut(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return err!(ErrorCode::MissingTokenAccounts);
+        };
+
+        // Gross up for transfer-fee mints so the vault receives the full amount
+        let transfer_amount = gross_amount_for_net(mint, deposit_amount)?;
+        let vault_before = vault.amount;
+
+        token_interface::transfer_checked(
+            CpiContext::new(
+                token_program.to_account_info(),
+                TransferChecked {
+                    from: from.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: vault.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                },
+            ),
+            transfer_amount,
+            mint.decimals,
+        )?;
+
+        // Only count what actually arrived in the vault
+        vault.reload()?;
+        let received = vault.amount - vault_before;
+
+        form.collected_amount += received;
+        msg!("Prize deposited: {} tokens", received);
+    }
+
+    // A fee change between quote and transfer can leave a shortfall,
+    // the form then stays in Draft until topped up
+    if form.collected_amount >= form.prize_pool {
+        transition(form, FormStatus::Funded)?;
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DepositPrize<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump,
+        has_one = authority
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // Token prize forms only
+    #[account(address = form.prize_mint)]
+    pub prize_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = form.prize_mint,
+        token::authority = authority
+    )]
+    pub authority_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", form.key().as_ref()],
+        bump
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::SysvarId;
+
+use crate::errors::ErrorCode;
+use crate::randomness::{draw_winners, latest_slot_hash, slot_hash_seed};
+use crate::state::*;
+
+pub fn handler(ctx: Context<DistributePrizes>) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+    let clock = Clock::get()?;
+
+    require!(
+        form.randomness_backend == RandomnessBackend::SlotHash,
+        ErrorCode::WrongRandomnessBackend
+    );
+    require!(
+        clock.unix_timestamp >= form.deadline,
+        ErrorCode::DeadlineNotReached
+    );
+    require!(
+        form.is_authorized(&ctx.accounts.authority.key(), PERMISSION_REQUEST_RANDOMNESS),
+        ErrorCode::Unauthorized
+    );
+    form.require_draw_ready(clock.unix_timestamp)?;
+
+    close_submissions(form)?;
+    form.check_transition(FormStatus::Settled)?;
+
+    // Note: deterministic and verifiable, but not cryptographically secure.
+    // Use the Switchboard or commit-reveal backend for high value pools.
+    let slot_hash = latest_slot_hash(&ctx.accounts.slot_hashes.data.borrow())
+        .ok_or(error!(ErrorCode::SlotHashUnavailable))?;
+    let random_value = slot_hash_seed(&slot_hash, &form.key(), form.participant_count);
+
+    form.random_value = random_value;
+    form.winners = draw_winners(form, &random_value, ctx.accounts.leaderboard.as_deref())?;
+    transition(form, FormStatus::Settled)?;
+
+    msg!(
+        "Prizes distributed. Random value: {:?}, Winners: {:?}",
+        random_value,
+        form.winners
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DistributePrizes<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Form authority, or an operator allowed to request randomness
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: SlotHashes sysvar, parsed manually (too large to deserialize)
+    #[account(address = SlotHashes::id())]
+    pub slot_hashes: AccountInfo<'info>,
+
+    // Skill-ranked forms only
+    #[account(
+        seeds = [b"leaderboard", form.key().as_ref()],
+        bump = leaderboard.bump,
+        has_one = form
+    )]
+    pub leaderboard: Option<Account<'info, Leaderboard>>,
+}
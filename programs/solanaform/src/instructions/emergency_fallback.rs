@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::SysvarId;
+
+use crate::errors::ErrorCode;
+use crate::randomness::{draw_winners, latest_slot_hash, slot_hash_seed, ORACLE_TIMEOUT_SECONDS};
+use crate::state::*;
+
+/// FIX: Issue 2 - Oracle Downtime
+pub fn handler(ctx: Context<EmergencyFallback>) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+    let clock = Clock::get()?;
+
+    require!(
+        form.randomness_backend == RandomnessBackend::Switchboard,
+        ErrorCode::WrongRandomnessBackend
+    );
+    require!(
+        form.status == FormStatus::RandomnessPending,
+        ErrorCode::RandomnessNotRequested
+    );
+    require!(
+        clock.unix_timestamp >= form.randomness_request_time + ORACLE_TIMEOUT_SECONDS,
+        ErrorCode::TooEarlyForFallback
+    );
+    require!(
+        ctx.accounts.authority.key() == form.authority,
+        ErrorCode::Unauthorized
+    );
+
+    // Generate fallback randomness from the most recent slot hash
+    let slot_hash = latest_slot_hash(&ctx.accounts.slot_hashes.data.borrow())
+        .ok_or(error!(ErrorCode::SlotHashUnavailable))?;
+    let random_value = slot_hash_seed(&slot_hash, &form.key(), form.participant_count);
+
+    form.random_value = random_value;
+    form.winners = draw_winners(form, &random_value, ctx.accounts.leaderboard.as_deref())?;
+    transition(form, FormStatus::Settled)?;
+    form.uses_fallback = true; // Mark that fallback was used
+
+    msg!("Emergency fallback activated. Using slot-based randomness.");
+    msg!("⚠️ WARNING: Fallback randomness is less secure than Switchboard VRF");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct EmergencyFallback<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump,
+        has_one = authority
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: SlotHashes sysvar, parsed manually (too large to deserialize)
+    #[account(address = SlotHashes::id())]
+    pub slot_hashes: AccountInfo<'info>,
+
+    // Skill-ranked forms only
+    #[account(
+        seeds = [b"leaderboard", form.key().as_ref()],
+        bump = leaderboard.bump,
+        has_one = form
+    )]
+    pub leaderboard: Option<Account<'info, Leaderboard>>,
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Only while submissions are still open, a deadline can never be brought forward
+pub fn handler(ctx: Context<ExtendDeadline>, new_deadline: i64) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+    let clock = Clock::get()?;
+
+    require!(
+        form.is_authorized(&ctx.accounts.authority.key(), PERMISSION_EXTEND_DEADLINE),
+        ErrorCode::Unauthorized
+    );
+    require!(
+        matches!(
+            form.status,
+            FormStatus::Draft | FormStatus::Funded | FormStatus::Open
+        ),
+        ErrorCode::FormInactive
+    );
+    require!(
+        clock.unix_timestamp < form.deadline,
+        ErrorCode::DeadlinePassed
+    );
+    require!(new_deadline > form.deadline, ErrorCode::InvalidDeadline);
+    ctx.accounts
+        .config
+        .check_deadline(new_deadline, clock.unix_timestamp)?;
+
+    form.deadline = new_deadline;
+
+    msg!("Deadline extended to: {}", new_deadline);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExtendDeadline<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Form authority, or an operator allowed to extend the deadline
+    pub authority: Signer<'info>,
+}
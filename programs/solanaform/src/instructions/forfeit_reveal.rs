@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::randomness::SLOT_HASHES_WINDOW;
+use crate::state::*;
+
+/// ANYONE can call this. The authority forfeits the pool, every participant
+/// can then claim an equal share through claim_prize.
+pub fn handler(ctx: Context<ForfeitReveal>) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+    let clock = Clock::get()?;
+
+    require!(
+        form.randomness_backend == RandomnessBackend::CommitReveal,
+        ErrorCode::WrongRandomnessBackend
+    );
+    require!(
+        form.status == FormStatus::RandomnessPending,
+        ErrorCode::RandomnessNotRequested
+    );
+    require!(
+        clock.slot > form.reveal_slot + SLOT_HASHES_WINDOW,
+        ErrorCode::TooEarlyForForfeit
+    );
+
+    form.reveal_forfeited = true;
+    transition(form, FormStatus::Settled)?;
+
+    msg!("Seed never revealed, prize pool forfeited to all participants");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ForfeitReveal<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+}
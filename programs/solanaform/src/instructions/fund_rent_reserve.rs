@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Lamports left in the reserve go back to the authority with close_form
+pub fn handler(ctx: Context<FundRentReserve>, amount: u64) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+
+    require!(
+        ctx.accounts.authority.key() == form.authority,
+        ErrorCode::Unauthorized
+    );
+    require!(
+        matches!(
+            form.status,
+            FormStatus::Draft | FormStatus::Funded | FormStatus::Open
+        ),
+        ErrorCode::FormInactive
+    );
+
+    // Transfer SOL from authority to form PDA
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: form.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    form.rent_reserve += amount;
+
+    msg!("Rent reserve funded: {} lamports", form.rent_reserve);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FundRentReserve<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump,
+        has_one = authority
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::program::SolanaForm;
+use crate::state::*;
+
+/// Only the program's upgrade authority can create the config (and treasury), once
+pub fn handler(
+    ctx: Context<InitializeConfig>,
+    admin: Pubkey,
+    protocol_fee_bps: u16,
+    treasury: Pubkey,
+    max_prize_pool: u64,
+    max_winners: u32,
+    max_duration: i64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.admin = admin;
+    config.protocol_fee_bps = protocol_fee_bps;
+    config.treasury = treasury;
+    config.max_prize_pool = max_prize_pool;
+    config.max_winners = max_winners;
+    config.max_duration = max_duration;
+    config.paused = false;
+    config.bump = ctx.bumps.config;
+    config.validate()?;
+
+    ctx.accounts.treasury.bump = ctx.bumps.treasury;
+
+    msg!("Config initialized, admin: {}", admin);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(
+        init,
+        payer = upgrade_authority,
+        space = 8 + Config::INIT_SPACE,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = upgrade_authority,
+        space = 8 + Treasury::INIT_SPACE,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, SolanaForm>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key())
+            @ ErrorCode::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(mut)]
+    pub upgrade_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
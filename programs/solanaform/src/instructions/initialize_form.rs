@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<InitializeForm>,
+    form_id: String,
+    prize_pool: u64,
+    deadline: i64,
+    max_participants: u32,
+    winner_count: u32,
+    randomness_backend: RandomnessBackend,
+    seed_commitment: Option<[u8; 32]>,
+    prize_schedule: PrizeSchedule,
+    allow_overfunding: bool,
+    entry_fee: u64,
+    creator_fee_bps: u16,
+    allowlist_root: Option<[u8; 32]>,
+    email_verifier: Option<Pubkey>,
+    questions_hash: [u8; 32],
+    schema_version: u16,
+    answer_key_commitment: Option<[u8; 32]>,
+    winner_selection: WinnerSelection,
+) -> Result<()> {
+    require!(
+        randomness_backend.is_enabled(),
+        ErrorCode::RandomnessBackendDisabled
+    );
+    require!(
+        seed_commitment.is_some() == (randomness_backend == RandomnessBackend::CommitReveal),
+        ErrorCode::InvalidSeedCommitment
+    );
+    require!(
+        ctx.accounts.prize_mint.is_some() == ctx.accounts.vault.is_some(),
+        ErrorCode::MissingTokenAccounts
+    );
+    require!(
+        (1..=MAX_WINNERS).contains(&winner_count) && winner_count <= max_participants,
+        ErrorCode::InvalidWinnerCount
+    );
+    prize_schedule.validate(prize_pool, winner_count)?;
+    require!(
+        winner_selection == WinnerSelection::Lottery
+            || (answer_key_commitment.is_some()
+                && randomness_backend != RandomnessBackend::AdminDeclared),
+        ErrorCode::InvalidWinnerSelection
+    );
+    require!(
+        creator_fee_bps as u64 <= BPS_DENOMINATOR,
+        ErrorCode::InvalidCreatorFee
+    );
+    let config = &ctx.accounts.config;
+    config.check_prize_pool(prize_pool)?;
+    config.check_deadline(deadline, Clock::get()?.unix_timestamp)?;
+    require!(
+        winner_count <= config.max_winners,
+        ErrorCode::ExceedsProtocolLimits
+    );
+
+    let form = &mut ctx.accounts.form;
+    form.authority = ctx.accounts.authority.key();
+    form.form_id = form_id;
+    form.prize_pool = prize_pool;
+    form.collected_amount = 0;
+    form.deadline = deadline;
+    form.max_participants = max_participants;
+    form.participant_count = 0;
+    // Nothing to deposit for prize-less forms
+    form.status = if prize_pool == 0 {
+        FormStatus::Funded
+    } else {
+        FormStatus::Draft
+    };
+    form.uses_fallback = false;
+    form.bump = ctx.bumps.form;
+    form.randomness_backend = randomness_backend;
+    form.randomness_account = Pubkey::default();
+    form.random_value = [0u8; 32];
+    form.winners = Vec::new();
+    form.seed_commitment = seed_commitment.unwrap_or_default();
+    form.entropy_accumulator = [0u8; 32];
+    form.reveal_slot = 0;
+    form.reveal_forfeited = false;
+    form.prize_mint = ctx
+        .accounts
+        .prize_mint
+        .as_ref()
+        .map(|mint| mint.key())
+        .unwrap_or_default();
+    form.prize_schedule = prize_schedule;
+    form.winner_count = winner_count;
+    form.claim_deadline = 0;
+    form.claimed_count = 0;
+    form.claimed_amount = 0;
+    form.allow_overfunding = allow_overfunding;
+    form.sponsored_amount = 0;
+    form.entry_fee = entry_fee;
+    form.creator_fee_bps = creator_fee_bps;
+    form.entry_fees_collected = 0;
+    form.creator_fees = 0;
+    form.allowlist_root = allowlist_root.unwrap_or_default();
+    form.email_verifier = email_verifier.unwrap_or_default();
+    form.email_claim_count = 0;
+    form.questions_hash = questions_hash;
+    form.schema_version = schema_version;
+    form.response_encryption_key = [0u8; 32];
+    form.answer_key_commitment = answer_key_commitment.unwrap_or_default();
+    form.answer_key = Vec::new();
+    form.answer_key_revealed = false;
+    form.eligibility_deadline = 0;
+    form.eligible_count = 0;
+    form.winner_selection = winner_selection;
+    form.rent_reserve = 0;
+    form.participation_gate = ParticipationGate::None;
+    form.pending_authority = Pubkey::default();
+    form.operators = Vec::new();
+    form.protocol_fee_bps = ctx.accounts.config.protocol_fee_bps;
+    form.response_count = 0;
+
+    msg!(
+        "Form initialized: {} ({:?})",
+        form.form_id,
+        form.randomness_backend
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct InitializeForm<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Form::INIT_SPACE,
+        seeds = [b"form", form_id.as_bytes()],
+        bump
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Optional: SPL Token or Token-2022 mint the prize is paid in (native SOL if omitted)
+    pub prize_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"vault", form.key().as_ref()],
+        bump,
+        token::mint = prize_mint,
+        token::authority = form,
+        token::token_program = token_program
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::quiz::quiz_answers_commitment;
+use crate::state::*;
+
+/// ANYONE can call this with the participant's answers and salt, so a crank
+/// can mark participants who shared them off-chain
+pub fn handler(ctx: Context<MarkEligible>, answers: Vec<u8>, salt: [u8; 32]) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+    let participant = &mut ctx.accounts.participant;
+    let clock = Clock::get()?;
+
+    require!(form.is_quiz(), ErrorCode::NotAQuiz);
+    require!(
+        form.winner_selection == WinnerSelection::Lottery,
+        ErrorCode::LotteryFormOnly
+    );
+    require!(form.answer_key_revealed, ErrorCode::AnswerKeyNotRevealed);
+    require!(form.status == FormStatus::Closed, ErrorCode::FormInactive);
+    require!(
+        clock.unix_timestamp <= form.eligibility_deadline,
+        ErrorCode::EligibilityWindowClosed
+    );
+    require!(!participant.eligible, ErrorCode::AlreadyEligible);
+    require!(
+        quiz_answers_commitment(&participant.wallet, &answers, &salt) == participant.responses_hash,
+        ErrorCode::InvalidQuizAnswers
+    );
+    require!(answers == form.answer_key, ErrorCode::IncorrectAnswers);
+
+    participant.eligible = true;
+    participant.eligible_index = form.eligible_count; // Sequential among eligible participants
+    form.eligible_count += 1;
+
+    msg!(
+        "Participant #{} eligible as #{}",
+        participant.participant_index,
+        participant.eligible_index
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MarkEligible<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"participant", form.key().as_ref(), participant.wallet.as_ref()],
+        bump = participant.bump,
+        has_one = form
+    )]
+    pub participant: Account<'info, Participant>,
+}
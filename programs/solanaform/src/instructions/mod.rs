@@ -0,0 +1,108 @@
+// Every instruction module exports its own `handler`, lib.rs always calls it qualified
+#![allow(ambiguous_glob_reexports)]
+
+pub mod accept_authority;
+pub mod cancel_form;
+pub mod check_winner_status;
+pub mod claim_prize;
+pub mod close_email_claim;
+pub mod close_form;
+pub mod close_participant;
+pub mod close_response;
+pub mod create_form_schema;
+pub mod deposit_prize;
+pub mod extend_deadline;
+pub mod fund_rent_reserve;
+pub mod initialize_config;
+pub mod initialize_form;
+pub mod mark_eligible;
+pub mod propose_authority;
+pub mod record_score;
+pub mod refund_sponsor;
+pub mod reveal_answer_key;
+pub mod set_allowlist;
+pub mod set_operator;
+pub mod set_participation_gate;
+pub mod set_paused;
+pub mod sponsor_deposit;
+pub mod submit_form;
+pub mod submit_response;
+pub mod sweep_unclaimed;
+pub mod update_config;
+pub mod update_response;
+pub mod withdraw_creator_fees;
+pub mod withdraw_submission;
+pub mod withdraw_treasury;
+
+#[cfg(feature = "slot-hash")]
+pub mod distribute_prizes;
+
+#[cfg(feature = "switchboard")]
+pub mod emergency_fallback;
+#[cfg(feature = "switchboard")]
+pub mod request_randomness;
+#[cfg(feature = "switchboard")]
+pub mod settle_randomness;
+
+#[cfg(feature = "commit-reveal")]
+pub mod forfeit_reveal;
+#[cfg(feature = "commit-reveal")]
+pub mod request_reveal;
+#[cfg(feature = "commit-reveal")]
+pub mod reveal_seed;
+
+#[cfg(feature = "admin-declared")]
+pub mod declare_winner;
+
+pub use accept_authority::*;
+pub use cancel_form::*;
+pub use check_winner_status::*;
+pub use claim_prize::*;
+pub use close_email_claim::*;
+pub use close_form::*;
+pub use close_participant::*;
+pub use close_response::*;
+pub use create_form_schema::*;
+pub use deposit_prize::*;
+pub use extend_deadline::*;
+pub use fund_rent_reserve::*;
+pub use initialize_config::*;
+pub use initialize_form::*;
+pub use mark_eligible::*;
+pub use propose_authority::*;
+pub use record_score::*;
+pub use refund_sponsor::*;
+pub use reveal_answer_key::*;
+pub use set_allowlist::*;
+pub use set_operator::*;
+pub use set_participation_gate::*;
+pub use set_paused::*;
+pub use sponsor_deposit::*;
+pub use submit_form::*;
+pub use submit_response::*;
+pub use sweep_unclaimed::*;
+pub use update_config::*;
+pub use update_response::*;
+pub use withdraw_creator_fees::*;
+pub use withdraw_submission::*;
+pub use withdraw_treasury::*;
+
+#[cfg(feature = "slot-hash")]
+pub use distribute_prizes::*;
+
+#[cfg(feature = "switchboard")]
+pub use emergency_fallback::*;
+#[cfg(feature = "switchboard")]
+pub use request_randomness::*;
+#[cfg(feature = "switchboard")]
+pub use settle_randomness::*;
+
+#[cfg(feature = "commit-reveal")]
+pub use forfeit_reveal::*;
+#[cfg(feature = "commit-reveal")]
+pub use request_reveal::*;
+#[cfg(feature = "commit-reveal")]
+pub use reveal_seed::*;
+
+#[cfg(feature = "admin-declared")]
+pub use declare_winner::*;
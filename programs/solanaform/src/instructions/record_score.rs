@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::quiz::quiz_answers_commitment;
+use crate::state::*;
+
+/// ANYONE can call this with the participant's answers and salt, the score
+/// is computed here and only the top scores are kept on the leaderboard
+pub fn handler(ctx: Context<RecordScore>, answers: Vec<u8>, salt: [u8; 32]) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+    let participant = &mut ctx.accounts.participant;
+    let clock = Clock::get()?;
+
+    require!(
+        form.winner_selection == WinnerSelection::SkillRanked,
+        ErrorCode::RankedFormOnly
+    );
+    require!(form.answer_key_revealed, ErrorCode::AnswerKeyNotRevealed);
+    require!(form.status == FormStatus::Closed, ErrorCode::FormInactive);
+    require!(
+        clock.unix_timestamp <= form.eligibility_deadline,
+        ErrorCode::EligibilityWindowClosed
+    );
+    require!(!participant.eligible, ErrorCode::AlreadyEligible);
+    require!(
+        answers.len() == form.answer_key.len()
+            && quiz_answers_commitment(&participant.wallet, &answers, &salt)
+                == participant.responses_hash,
+        ErrorCode::InvalidQuizAnswers
+    );
+
+    let score = answers
+        .iter()
+        .zip(&form.answer_key)
+        .filter(|(answer, key)| answer == key)
+        .count() as u32;
+
+    participant.eligible = true;
+    participant.score = score;
+    form.eligible_count += 1;
+    ctx.accounts
+        .leaderboard
+        .insert(participant.participant_index, score, form.winner_count)?;
+
+    msg!(
+        "Participant #{} scored {}",
+        participant.participant_index,
+        score
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RecordScore<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"participant", form.key().as_ref(), participant.wallet.as_ref()],
+        bump = participant.bump,
+        has_one = form
+    )]
+    pub participant: Account<'info, Participant>,
+
+    #[account(
+        mut,
+        seeds = [b"leaderboard", form.key().as_ref()],
+        bump = leaderboard.bump,
+        has_one = form
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+}
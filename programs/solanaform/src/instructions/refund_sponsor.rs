@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::vault::transfer_from_vault;
+
+/// ANYONE can call this, each sponsor gets back exactly what they put in
+pub fn handler(ctx: Context<RefundSponsor>) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+    let refund_amount = ctx.accounts.sponsorship.amount;
+
+    require!(
+        form.status == FormStatus::Cancelled,
+        ErrorCode::FormNotCancelled
+    );
+
+    if form.is_token_pool() {
+        let (Some(mint), Some(vault), Some(to), Some(token_program)) = (
+            ctx.accounts.prize_mint.as_ref(),
+            ctx.accounts.vault.as_ref(),
+            ctx.accounts.sponsor_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return err!(ErrorCode::MissingTokenAccounts);
+        };
+
+        transfer_from_vault(form, vault, mint, to, token_program, refund_amount)?;
+    } else {
+        **form.to_account_info().try_borrow_mut_lamports()? -= refund_amount;
+        **ctx.accounts.sponsor.try_borrow_mut_lamports()? += refund_amount;
+    }
+
+    form.collected_amount -= refund_amount;
+    form.sponsored_amount -= refund_amount;
+
+    // Sponsorship rent is refunded to the sponsor via close constraint
+    msg!(
+        "Sponsor {} refunded {}",
+        ctx.accounts.sponsor.key(),
+        refund_amount
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RefundSponsor<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"sponsorship", form.key().as_ref(), sponsor.key().as_ref()],
+        bump = sponsorship.bump,
+        has_one = form,
+        has_one = sponsor,
+        close = sponsor
+    )]
+    pub sponsorship: Account<'info, Sponsorship>,
+
+    /// CHECK: Sponsor receiving the refund (must match sponsorship.sponsor)
+    #[account(mut)]
+    pub sponsor: AccountInfo<'info>,
+
+    // Token prize forms only
+    #[account(address = form.prize_mint)]
+    pub prize_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", form.key().as_ref()],
+        bump
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = form.prize_mint,
+        token::authority = sponsor
+    )]
+    pub sponsor_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
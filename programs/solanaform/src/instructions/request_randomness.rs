@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use switchboard_on_demand::accounts::RandomnessAccountData;
+use switchboard_on_demand::get_switchboard_on_demand_program_id;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+pub fn handler(ctx: Context<RequestRandomness>) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+    let clock = Clock::get()?;
+
+    require!(
+        form.randomness_backend == RandomnessBackend::Switchboard,
+        ErrorCode::WrongRandomnessBackend
+    );
+    require!(
+        clock.unix_timestamp >= form.deadline,
+        ErrorCode::DeadlineNotReached
+    );
+    require!(
+        form.is_authorized(&ctx.accounts.authority.key(), PERMISSION_REQUEST_RANDOMNESS),
+        ErrorCode::Unauthorized
+    );
+    form.require_draw_ready(clock.unix_timestamp)?;
+
+    // Only accept randomness committed in the previous slot, an account that
+    // was committed earlier (or already revealed) has a value the caller may know
+    let randomness_data =
+        RandomnessAccountData::parse(ctx.accounts.randomness_account.data.borrow())
+            .map_err(|_| error!(ErrorCode::SwitchboardError))?;
+    require!(
+        randomness_data.seed_slot == clock.slot.saturating_sub(1),
+        ErrorCode::StaleRandomness
+    );
+
+    close_submissions(form)?;
+    transition(form, FormStatus::RandomnessPending)?;
+
+    form.randomness_account = ctx.accounts.randomness_account.key();
+    form.randomness_request_time = clock.unix_timestamp;
+
+    msg!("Randomness requested at timestamp: {}", clock.unix_timestamp);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RequestRandomness<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Form authority, or an operator allowed to request randomness
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: The Switchboard randomness account, parsed in the handler
+    #[account(owner = get_switchboard_on_demand_program_id())]
+    pub randomness_account: AccountInfo<'info>,
+}
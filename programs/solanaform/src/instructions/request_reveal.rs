@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::randomness::REVEAL_SLOT_DELAY;
+use crate::state::*;
+
+/// ANYONE can call this once the deadline has passed
+pub fn handler(ctx: Context<RequestReveal>) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+    let clock = Clock::get()?;
+
+    require!(
+        form.randomness_backend == RandomnessBackend::CommitReveal,
+        ErrorCode::WrongRandomnessBackend
+    );
+    require!(
+        clock.unix_timestamp >= form.deadline,
+        ErrorCode::DeadlineNotReached
+    );
+    form.require_draw_ready(clock.unix_timestamp)?;
+
+    close_submissions(form)?;
+    transition(form, FormStatus::RandomnessPending)?;
+
+    form.reveal_slot = clock.slot + REVEAL_SLOT_DELAY;
+    form.randomness_request_time = clock.unix_timestamp;
+
+    msg!(
+        "Reveal requested, seed will use slot {} or the next produced one",
+        form.reveal_slot
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RequestReveal<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    // NOTE: No authority required, the authority cannot pick the slot
+}
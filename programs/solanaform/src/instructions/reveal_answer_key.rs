@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::quiz::answer_key_commitment;
+use crate::state::*;
+
+/// Opens the eligibility window, winners can only be drawn once it has ended
+pub fn handler(ctx: Context<RevealAnswerKey>, answer_key: Vec<u8>, salt: [u8; 32]) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+    let clock = Clock::get()?;
+
+    require!(form.is_quiz(), ErrorCode::NotAQuiz);
+    require!(
+        ctx.accounts.authority.key() == form.authority,
+        ErrorCode::Unauthorized
+    );
+    require!(
+        clock.unix_timestamp >= form.deadline,
+        ErrorCode::DeadlineNotReached
+    );
+    require!(
+        !form.answer_key_revealed,
+        ErrorCode::AnswerKeyAlreadyRevealed
+    );
+    require!(
+        !answer_key.is_empty()
+            && answer_key.len() <= MAX_QUESTIONS as usize
+            && answer_key_commitment(&answer_key, &salt) == form.answer_key_commitment,
+        ErrorCode::InvalidAnswerKey
+    );
+
+    close_submissions(form)?;
+    require!(form.status == FormStatus::Closed, ErrorCode::FormInactive);
+
+    if form.winner_selection == WinnerSelection::SkillRanked {
+        let leaderboard = ctx
+            .accounts
+            .leaderboard
+            .as_mut()
+            .ok_or(error!(ErrorCode::MissingLeaderboard))?;
+        leaderboard.form = form.key();
+        leaderboard.entries = Vec::new();
+        leaderboard.bump = ctx.bumps.leaderboard.unwrap_or_default();
+    }
+
+    form.answer_key = answer_key;
+    form.answer_key_revealed = true;
+    form.eligibility_deadline = clock.unix_timestamp + ELIGIBILITY_WINDOW_SECONDS;
+
+    msg!(
+        "Answer key revealed, participants can prove eligibility until {}",
+        form.eligibility_deadline
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevealAnswerKey<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump,
+        has_one = authority
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // Skill-ranked forms only
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Leaderboard::INIT_SPACE,
+        seeds = [b"leaderboard", form.key().as_ref()],
+        bump
+    )]
+    pub leaderboard: Option<Account<'info, Leaderboard>>,
+
+    pub system_program: Program<'info, System>,
+}
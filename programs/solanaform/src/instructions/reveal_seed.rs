@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::SysvarId;
+
+use crate::errors::ErrorCode;
+use crate::randomness::{commit_reveal_seed, draw_winners, find_slot_hash, seed_commitment};
+use crate::state::*;
+
+pub fn handler(ctx: Context<RevealSeed>, secret: [u8; 32]) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+    let clock = Clock::get()?;
+
+    require!(
+        form.randomness_backend == RandomnessBackend::CommitReveal,
+        ErrorCode::WrongRandomnessBackend
+    );
+    require!(
+        form.status == FormStatus::RandomnessPending,
+        ErrorCode::RandomnessNotRequested
+    );
+    require!(
+        seed_commitment(&secret) == form.seed_commitment,
+        ErrorCode::InvalidSeedReveal
+    );
+    require!(clock.slot > form.reveal_slot, ErrorCode::RevealSlotNotReached);
+
+    let slot_hash = find_slot_hash(&ctx.accounts.slot_hashes.data.borrow(), form.reveal_slot)
+        .ok_or(error!(ErrorCode::SlotHashUnavailable))?;
+
+    let random_value = commit_reveal_seed(&secret, &form.entropy_accumulator, &slot_hash);
+
+    form.random_value = random_value;
+    form.winners = draw_winners(form, &random_value, ctx.accounts.leaderboard.as_deref())?;
+    transition(form, FormStatus::Settled)?;
+
+    msg!("Seed revealed. Value: {:?}", random_value);
+    msg!("Winners: {:?}", form.winners);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevealSeed<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump,
+        has_one = authority
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    pub authority: Signer<'info>,
+
+    /// CHECK: SlotHashes sysvar, parsed manually (too large to deserialize)
+    #[account(address = SlotHashes::id())]
+    pub slot_hashes: AccountInfo<'info>,
+
+    // Skill-ranked forms only
+    #[account(
+        seeds = [b"leaderboard", form.key().as_ref()],
+        bump = leaderboard.bump,
+        has_one = form
+    )]
+    pub leaderboard: Option<Account<'info, Leaderboard>>,
+}
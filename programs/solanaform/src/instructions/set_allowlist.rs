@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Wallets already registered stay registered when the root changes
+pub fn handler(ctx: Context<SetAllowlist>, allowlist_root: Option<[u8; 32]>) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+
+    require!(
+        form.is_authorized(&ctx.accounts.authority.key(), PERMISSION_EDIT_METADATA),
+        ErrorCode::Unauthorized
+    );
+    require!(
+        matches!(
+            form.status,
+            FormStatus::Draft | FormStatus::Funded | FormStatus::Open
+        ),
+        ErrorCode::FormInactive
+    );
+
+    form.allowlist_root = allowlist_root.unwrap_or_default();
+
+    msg!("Allowlist updated, gated: {}", form.is_allowlisted());
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetAllowlist<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Form authority, or an operator allowed to edit the form metadata
+    pub authority: Signer<'info>,
+}
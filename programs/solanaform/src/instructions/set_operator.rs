@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Adds `operator` or replaces its permissions, 0 permissions removes it
+pub fn handler(ctx: Context<SetOperator>, operator: Pubkey, permissions: u8) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+
+    require!(
+        ctx.accounts.authority.key() == form.authority,
+        ErrorCode::Unauthorized
+    );
+    require!(
+        permissions & !ALL_PERMISSIONS == 0,
+        ErrorCode::InvalidPermissions
+    );
+
+    let existing = form
+        .operators
+        .iter()
+        .position(|entry| entry.key == operator);
+    match (existing, permissions) {
+        (Some(index), 0) => {
+            form.operators.remove(index);
+        }
+        (Some(index), _) => form.operators[index].permissions = permissions,
+        (None, 0) => {}
+        (None, _) => {
+            require!(
+                form.operators.len() < MAX_OPERATORS as usize,
+                ErrorCode::TooManyOperators
+            );
+            form.operators.push(Operator {
+                key: operator,
+                permissions,
+            });
+        }
+    }
+
+    msg!(
+        "Operator {} permissions set to {:#05b}",
+        operator,
+        permissions
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetOperator<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump,
+        has_one = authority
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    pub authority: Signer<'info>,
+}
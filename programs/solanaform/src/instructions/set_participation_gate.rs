@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Wallets already registered stay registered when the gate changes
+pub fn handler(ctx: Context<SetParticipationGate>, gate: ParticipationGate) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+
+    require!(
+        form.is_authorized(&ctx.accounts.authority.key(), PERMISSION_EDIT_METADATA),
+        ErrorCode::Unauthorized
+    );
+    require!(
+        matches!(
+            form.status,
+            FormStatus::Draft | FormStatus::Funded | FormStatus::Open
+        ),
+        ErrorCode::FormInactive
+    );
+
+    form.participation_gate = gate;
+
+    msg!("Participation gate updated: {:?}", form.participation_gate);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetParticipationGate<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Form authority, or an operator allowed to edit the form metadata
+    pub authority: Signer<'info>,
+}
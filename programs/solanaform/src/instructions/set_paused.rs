@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Works while paused, so the admin can always unpause
+/// Winner checks and claims are never paused, winners could otherwise lose their claim window
+pub fn handler(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    require!(
+        ctx.accounts.admin.key() == config.admin,
+        ErrorCode::Unauthorized
+    );
+
+    config.paused = paused;
+
+    msg!("Program paused: {}", paused);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
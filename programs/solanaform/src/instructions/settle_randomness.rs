@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use switchboard_on_demand::accounts::RandomnessAccountData;
+use switchboard_on_demand::get_switchboard_on_demand_program_id;
+
+use crate::errors::ErrorCode;
+use crate::randomness::draw_winners;
+use crate::state::*;
+
+pub fn handler(ctx: Context<SettleRandomness>) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+    let clock = Clock::get()?;
+
+    require!(
+        form.randomness_backend == RandomnessBackend::Switchboard,
+        ErrorCode::WrongRandomnessBackend
+    );
+    form.check_transition(FormStatus::Settled)?;
+    require!(
+        form.is_authorized(&ctx.accounts.authority.key(), PERMISSION_REQUEST_RANDOMNESS),
+        ErrorCode::Unauthorized
+    );
+
+    // Parse the randomness account data
+    let randomness_data =
+        RandomnessAccountData::parse(ctx.accounts.randomness_account.data.borrow())
+            .map_err(|_| error!(ErrorCode::SwitchboardError))?;
+
+    // Get the random value
+    let random_value = randomness_data
+        .get_value(clock.slot)
+        .map_err(|_| error!(ErrorCode::RandomnessNotResolved))?;
+
+    form.random_value = random_value;
+    form.winners = draw_winners(form, &random_value, ctx.accounts.leaderboard.as_deref())?;
+    transition(form, FormStatus::Settled)?;
+
+    msg!("Randomness settled. Value: {:?}", random_value);
+    msg!("Winners: {:?}", form.winners);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SettleRandomness<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Form authority, or an operator allowed to request randomness
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: The Switchboard randomness account locked by request_randomness
+    #[account(
+        owner = get_switchboard_on_demand_program_id(),
+        constraint = randomness_account.key() == form.randomness_account
+    )]
+    pub randomness_account: AccountInfo<'info>,
+
+    // Skill-ranked forms only
+    #[account(
+        seeds = [b"leaderboard", form.key().as_ref()],
+        bump = leaderboard.bump,
+        has_one = form
+    )]
+    pub leaderboard: Option<Account<'info, Leaderboard>>,
+}
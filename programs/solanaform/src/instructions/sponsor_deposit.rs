@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Open to any signer while the form is not closed yet
+pub fn handler(ctx: Context<SponsorDeposit>, amount: u64) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+    let sponsorship = &mut ctx.accounts.sponsorship;
+
+    require!(amount > 0, ErrorCode::InvalidSponsorshipAmount);
+    require!(
+        matches!(
+            form.status,
+            FormStatus::Draft | FormStatus::Funded | FormStatus::Open
+        ),
+        ErrorCode::FormInactive
+    );
+    let new_total = form
+        .collected_amount
+        .checked_add(amount)
+        .ok_or(error!(ErrorCode::MathOverflow))?;
+    require!(
+        form.allow_overfunding || new_total <= form.prize_pool,
+        ErrorCode::SponsorshipExceedsPool
+    );
+    ctx.accounts.config.check_prize_pool(new_total)?;
+
+    let received = if !form.is_token_pool() {
+        // Transfer SOL from sponsor to form PDA
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.sponsor.to_account_info(),
+                    to: form.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        amount
+    } else {
+        let (Some(mint), Some(from), Some(vault), Some(token_program)) = (
+            ctx.accounts.prize_mint.as_ref(),
+            ctx.accounts.sponsor_token_account.as_ref(),
+            ctx.accounts.vault.as_mut(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return err!(ErrorCode::MissingTokenAccounts);
+        };
+
+        let vault_before = vault.amount;
+        token_interface::transfer_checked(
+            CpiContext::new(
+                token_program.to_account_info(),
+                TransferChecked {
+                    from: from.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: vault.to_account_info(),
+                    authority: ctx.accounts.sponsor.to_account_info(),
+                },
+            ),
+            amount,
+            mint.decimals,
+        )?;
+
+        // Only count what actually arrived in the vault (transfer-fee mints)
+        vault.reload()?;
+        vault.amount - vault_before
+    };
+
+    sponsorship.form = form.key();
+    sponsorship.sponsor = ctx.accounts.sponsor.key();
+    sponsorship.amount += received;
+    sponsorship.bump = ctx.bumps.sponsorship;
+
+    form.collected_amount += received;
+    form.sponsored_amount += received;
+
+    if form.status == FormStatus::Draft && form.collected_amount >= form.prize_pool {
+        transition(form, FormStatus::Funded)?;
+    }
+
+    msg!(
+        "Sponsor {} deposited {}",
+        ctx.accounts.sponsor.key(),
+        received
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SponsorDeposit<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = sponsor,
+        space = 8 + Sponsorship::INIT_SPACE,
+        seeds = [b"sponsorship", form.key().as_ref(), sponsor.key().as_ref()],
+        bump
+    )]
+    pub sponsorship: Account<'info, Sponsorship>,
+
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    // Token prize forms only
+    #[account(address = form.prize_mint)]
+    pub prize_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = form.prize_mint,
+        token::authority = sponsor
+    )]
+    pub sponsor_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", form.key().as_ref()],
+        bump
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,256 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::allowlist::{allowlist_leaf, verify_allowlist_proof};
+use crate::attestation::{attestation_message, verify_attestation};
+use crate::errors::ErrorCode;
+use crate::gate::check_participation_gate;
+use crate::randomness::accumulate_entropy;
+use crate::state::*;
+use crate::vault::gross_amount_for_net;
+
+pub fn handler(
+    ctx: Context<SubmitForm>,
+    email_hash: [u8; 32],
+    allowlist_proof: Vec<[u8; 32]>,
+    allocation: u64,
+    attestation_expiry: i64,
+    responses_hash: [u8; 32],
+    schema_version: u16,
+) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+    let participant = &mut ctx.accounts.participant;
+    let clock = Clock::get()?;
+
+    if form.status != FormStatus::Open {
+        transition(form, FormStatus::Open)?;
+    }
+    require!(
+        clock.unix_timestamp < form.deadline,
+        ErrorCode::DeadlinePassed
+    );
+    require!(
+        form.participant_count < form.max_participants,
+        ErrorCode::MaxParticipantsReached
+    );
+    require!(
+        schema_version == form.schema_version,
+        ErrorCode::SchemaVersionMismatch
+    );
+    if form.is_allowlisted() {
+        let leaf = allowlist_leaf(&ctx.accounts.user.key(), allocation);
+        require!(
+            verify_allowlist_proof(&form.allowlist_root, leaf, &allowlist_proof),
+            ErrorCode::NotAllowlisted
+        );
+    }
+    check_participation_gate(
+        &form.participation_gate,
+        ctx.accounts.gate_token_account.as_ref(),
+        ctx.accounts.gate_metadata.as_deref(),
+    )?;
+    if form.requires_email_attestation() {
+        require!(
+            clock.unix_timestamp <= attestation_expiry,
+            ErrorCode::EmailAttestationExpired
+        );
+        let instructions_sysvar = ctx
+            .accounts
+            .instructions_sysvar
+            .as_ref()
+            .ok_or(error!(ErrorCode::MissingEmailAttestation))?;
+        let message = attestation_message(
+            &form.key(),
+            &ctx.accounts.user.key(),
+            &email_hash,
+            attestation_expiry,
+        );
+        verify_attestation(instructions_sysvar, &form.email_verifier, &message)?;
+    }
+
+    participant.wallet = ctx.accounts.user.key();
+    participant.form = form.key();
+    participant.email_hash = email_hash;
+    participant.timestamp = clock.unix_timestamp;
+    participant.participant_index = form.participant_count; // IMPORTANT: Sequential index
+    participant.is_winner = false;
+    participant.claimed = false;
+    participant.bump = ctx.bumps.participant;
+    participant.entry_fee_paid = 0;
+    participant.allocation = if form.is_allowlisted() { allocation } else { 0 };
+    participant.responses_hash = responses_hash;
+    participant.schema_version = schema_version;
+    participant.eligible = false;
+    participant.eligible_index = 0;
+    participant.score = 0;
+
+    let email_claim = &mut ctx.accounts.email_claim;
+    email_claim.form = form.key();
+    email_claim.wallet = ctx.accounts.user.key();
+    email_claim.bump = ctx.bumps.email_claim;
+    form.email_claim_count += 1;
+
+    // Gasless submission: the relayer paid the rent, the form's reserve pays it back
+    let rent_sponsored = ctx.accounts.payer.key() != ctx.accounts.user.key();
+    if rent_sponsored {
+        let reimbursement =
+            participant.to_account_info().lamports() + email_claim.to_account_info().lamports();
+        require!(
+            form.rent_reserve >= reimbursement,
+            ErrorCode::RentReserveExhausted
+        );
+
+        **form.to_account_info().try_borrow_mut_lamports()? -= reimbursement;
+        **ctx.accounts.payer.try_borrow_mut_lamports()? += reimbursement;
+        form.rent_reserve -= reimbursement;
+    }
+    participant.rent_sponsored = rent_sponsored;
+    email_claim.rent_sponsored = rent_sponsored;
+
+    if form.entry_fee > 0 {
+        let received = if !form.is_token_pool() {
+            // Transfer SOL from participant to form PDA
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.user.to_account_info(),
+                        to: form.to_account_info(),
+                    },
+                ),
+                form.entry_fee,
+            )?;
+            form.entry_fee
+        } else {
+            let (Some(mint), Some(from), Some(vault), Some(token_program)) = (
+                ctx.accounts.prize_mint.as_ref(),
+                ctx.accounts.user_token_account.as_ref(),
+                ctx.accounts.vault.as_mut(),
+                ctx.accounts.token_program.as_ref(),
+            ) else {
+                return err!(ErrorCode::MissingTokenAccounts);
+            };
+
+            // Gross up for transfer-fee mints so the vault receives the full fee
+            let transfer_amount = gross_amount_for_net(mint, form.entry_fee)?;
+            let vault_before = vault.amount;
+
+            token_interface::transfer_checked(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    TransferChecked {
+                        from: from.to_account_info(),
+                        mint: mint.to_account_info(),
+                        to: vault.to_account_info(),
+                        authority: ctx.accounts.user.to_account_info(),
+                    },
+                ),
+                transfer_amount,
+                mint.decimals,
+            )?;
+
+            // Only count what actually arrived in the vault
+            vault.reload()?;
+            vault.amount - vault_before
+        };
+
+        let (pool_share, creator_cut) = split_entry_fee(received, form.creator_fee_bps);
+        form.collected_amount += pool_share;
+        form.creator_fees += creator_cut;
+        form.entry_fees_collected += received;
+        participant.entry_fee_paid = received;
+    }
+
+    // Every participant contributes entropy the authority cannot know at commit time
+    if form.randomness_backend == RandomnessBackend::CommitReveal {
+        form.entropy_accumulator =
+            accumulate_entropy(&form.entropy_accumulator, &ctx.accounts.user.key());
+    }
+
+    form.participant_count += 1;
+
+    msg!(
+        "Participant #{} registered: {}",
+        participant.participant_index,
+        ctx.accounts.user.key()
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(email_hash: [u8; 32])]
+pub struct SubmitForm<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Participant::INIT_SPACE,
+        seeds = [b"participant", form.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub participant: Account<'info, Participant>,
+
+    // Fails if another wallet already registered this email
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + EmailClaim::INIT_SPACE,
+        seeds = [b"email", form.key().as_ref(), email_hash.as_ref()],
+        bump
+    )]
+    pub email_claim: Account<'info, EmailClaim>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    // Pays the rent, the user itself or a relayer for gasless submissions
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // Token prize forms with an entry fee only
+    #[account(address = form.prize_mint)]
+    pub prize_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = form.prize_mint,
+        token::authority = user
+    )]
+    pub user_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", form.key().as_ref()],
+        bump
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    // Token or NFT gated forms only
+    #[account(token::authority = user)]
+    pub gate_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Metaplex metadata of the gate NFT, checked in check_participation_gate
+    pub gate_metadata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Instructions sysvar, email-verified forms only
+    #[account(address = solana_instructions_sysvar::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Participant must have submitted the form first
+pub fn handler(ctx: Context<SubmitResponse>, payload: ResponsePayload) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+    let form_schema = &ctx.accounts.form_schema;
+    let clock = Clock::get()?;
+
+    require!(form.status == FormStatus::Open, ErrorCode::FormInactive);
+    require!(
+        clock.unix_timestamp < form.deadline,
+        ErrorCode::DeadlinePassed
+    );
+    require!(
+        8 + Response::space_for(&payload) <= form_schema.max_response_size as usize,
+        ErrorCode::ResponseTooLarge
+    );
+    form_schema.validate_payload(&payload, form.encrypts_responses())?;
+
+    let response = &mut ctx.accounts.response;
+    response.form = form.key();
+    response.wallet = ctx.accounts.wallet.key();
+    response.schema_version = form_schema.schema_version;
+    response.payload = payload;
+    response.bump = ctx.bumps.response;
+    form.response_count += 1;
+
+    msg!("Response stored for {}", ctx.accounts.wallet.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(payload: ResponsePayload)]
+pub struct SubmitResponse<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [b"schema", form.key().as_ref()],
+        bump = form_schema.bump,
+        has_one = form
+    )]
+    pub form_schema: Account<'info, FormSchema>,
+
+    #[account(
+        seeds = [b"participant", form.key().as_ref(), wallet.key().as_ref()],
+        bump = participant.bump,
+        has_one = form,
+        has_one = wallet
+    )]
+    pub participant: Account<'info, Participant>,
+
+    #[account(
+        init,
+        payer = wallet,
+        space = 8 + Response::space_for(&payload),
+        seeds = [b"response", form.key().as_ref(), wallet.key().as_ref()],
+        bump
+    )]
+    pub response: Account<'info, Response>,
+
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::vault::transfer_from_vault;
+
+/// Claims are closed afterwards, close_form can then reclaim the rent
+pub fn handler(ctx: Context<SweepUnclaimed>) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+    let clock = Clock::get()?;
+
+    require!(
+        ctx.accounts.authority.key() == form.authority,
+        ErrorCode::Unauthorized
+    );
+    require!(form.status == FormStatus::Settled, ErrorCode::NotDistributed);
+    require!(
+        clock.unix_timestamp > form.claim_deadline,
+        ErrorCode::ClaimWindowOpen
+    );
+    transition(form, FormStatus::Finalized)?;
+
+    let unclaimed_amount = form.collected_amount - form.claimed_amount;
+
+    if form.is_token_pool() {
+        let (Some(mint), Some(vault), Some(to), Some(token_program)) = (
+            ctx.accounts.prize_mint.as_ref(),
+            ctx.accounts.vault.as_ref(),
+            ctx.accounts.authority_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return err!(ErrorCode::MissingTokenAccounts);
+        };
+
+        if unclaimed_amount > 0 {
+            transfer_from_vault(form, vault, mint, to, token_program, unclaimed_amount)?;
+        }
+    } else {
+        // Only the unclaimed remainder, rent stays until close_form
+        **form.to_account_info().try_borrow_mut_lamports()? -= unclaimed_amount;
+        **ctx.accounts.authority.try_borrow_mut_lamports()? += unclaimed_amount;
+    }
+
+    form.collected_amount = form.claimed_amount;
+
+    msg!("Unclaimed prizes swept: {}", unclaimed_amount);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SweepUnclaimed<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump,
+        has_one = authority
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // Token prize forms only
+    #[account(address = form.prize_mint)]
+    pub prize_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", form.key().as_ref()],
+        bump
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = form.prize_mint,
+        token::authority = authority
+    )]
+    pub authority_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
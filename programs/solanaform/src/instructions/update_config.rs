@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Limits only apply to forms created (or deadlines extended) afterwards
+pub fn handler(
+    ctx: Context<UpdateConfig>,
+    admin: Pubkey,
+    protocol_fee_bps: u16,
+    treasury: Pubkey,
+    max_prize_pool: u64,
+    max_winners: u32,
+    max_duration: i64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    require!(
+        ctx.accounts.admin.key() == config.admin,
+        ErrorCode::Unauthorized
+    );
+
+    config.admin = admin;
+    config.protocol_fee_bps = protocol_fee_bps;
+    config.treasury = treasury;
+    config.max_prize_pool = max_prize_pool;
+    config.max_winners = max_winners;
+    config.max_duration = max_duration;
+    config.validate()?;
+
+    msg!("Config updated, admin: {}", admin);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// The account grows or shrinks to the new payload, rent is settled with the wallet
+pub fn handler(ctx: Context<UpdateResponse>, payload: ResponsePayload) -> Result<()> {
+    let form = &ctx.accounts.form;
+    let form_schema = &ctx.accounts.form_schema;
+    let clock = Clock::get()?;
+
+    require!(form.status == FormStatus::Open, ErrorCode::FormInactive);
+    require!(
+        clock.unix_timestamp < form.deadline,
+        ErrorCode::DeadlinePassed
+    );
+    require!(
+        8 + Response::space_for(&payload) <= form_schema.max_response_size as usize,
+        ErrorCode::ResponseTooLarge
+    );
+    form_schema.validate_payload(&payload, form.encrypts_responses())?;
+
+    ctx.accounts.response.payload = payload;
+
+    msg!("Response updated for {}", ctx.accounts.wallet.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(payload: ResponsePayload)]
+pub struct UpdateResponse<'info> {
+    #[account(
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [b"schema", form.key().as_ref()],
+        bump = form_schema.bump,
+        has_one = form
+    )]
+    pub form_schema: Account<'info, FormSchema>,
+
+    #[account(
+        mut,
+        seeds = [b"response", form.key().as_ref(), wallet.key().as_ref()],
+        bump = response.bump,
+        has_one = form,
+        has_one = wallet,
+        realloc = 8 + Response::space_for(&payload),
+        realloc::payer = wallet,
+        realloc::zero = false
+    )]
+    pub response: Account<'info, Response>,
+
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
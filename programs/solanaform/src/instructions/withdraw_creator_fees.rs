@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::vault::transfer_from_vault;
+
+/// Locked until randomness is requested, a cancelled form refunds the
+/// full entry fees to participants instead
+pub fn handler(ctx: Context<WithdrawCreatorFees>) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+
+    require!(
+        ctx.accounts.authority.key() == form.authority,
+        ErrorCode::Unauthorized
+    );
+    require!(
+        matches!(
+            form.status,
+            FormStatus::RandomnessPending | FormStatus::Settled | FormStatus::Finalized
+        ),
+        ErrorCode::CreatorFeesLocked
+    );
+
+    let withdraw_amount = form.creator_fees;
+
+    if form.is_token_pool() {
+        let (Some(mint), Some(vault), Some(to), Some(token_program)) = (
+            ctx.accounts.prize_mint.as_ref(),
+            ctx.accounts.vault.as_ref(),
+            ctx.accounts.authority_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return err!(ErrorCode::MissingTokenAccounts);
+        };
+
+        if withdraw_amount > 0 {
+            transfer_from_vault(form, vault, mint, to, token_program, withdraw_amount)?;
+        }
+    } else {
+        **form.to_account_info().try_borrow_mut_lamports()? -= withdraw_amount;
+        **ctx.accounts.authority.try_borrow_mut_lamports()? += withdraw_amount;
+    }
+
+    form.creator_fees = 0;
+    form.entry_fees_collected -= withdraw_amount;
+
+    msg!("Creator fees withdrawn: {}", withdraw_amount);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawCreatorFees<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump,
+        has_one = authority
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // Token prize forms only
+    #[account(address = form.prize_mint)]
+    pub prize_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", form.key().as_ref()],
+        bump
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = form.prize_mint,
+        token::authority = authority
+    )]
+    pub authority_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::vault::transfer_from_vault;
+
+/// The last participant takes over the freed index, so indices stay 0..participant_count
+/// and winner selection over them is unchanged. Once nobody is left the form is Funded
+/// again, as before its first submission
+pub fn handler(ctx: Context<WithdrawSubmission>) -> Result<()> {
+    let form = &mut ctx.accounts.form;
+    let participant = &ctx.accounts.participant;
+    let clock = Clock::get()?;
+
+    require!(form.status == FormStatus::Open, ErrorCode::FormInactive);
+    require!(
+        clock.unix_timestamp < form.deadline,
+        ErrorCode::DeadlinePassed
+    );
+
+    let index = participant.participant_index;
+    let last_index = form.participant_count - 1;
+    if index != last_index {
+        let last_participant = ctx
+            .accounts
+            .last_participant
+            .as_mut()
+            .ok_or(error!(ErrorCode::InvalidLastParticipant))?;
+        require!(
+            last_participant.participant_index == last_index,
+            ErrorCode::InvalidLastParticipant
+        );
+        last_participant.participant_index = index;
+    }
+
+    // Refund the entry fee, the creator's cut is given up
+    let refund_amount = participant.entry_fee_paid;
+    if refund_amount > 0 {
+        if form.is_token_pool() {
+            let (Some(mint), Some(vault), Some(to), Some(token_program)) = (
+                ctx.accounts.prize_mint.as_ref(),
+                ctx.accounts.vault.as_ref(),
+                ctx.accounts.wallet_token_account.as_ref(),
+                ctx.accounts.token_program.as_ref(),
+            ) else {
+                return err!(ErrorCode::MissingTokenAccounts);
+            };
+
+            transfer_from_vault(form, vault, mint, to, token_program, refund_amount)?;
+        } else {
+            **form.to_account_info().try_borrow_mut_lamports()? -= refund_amount;
+            **ctx.accounts.wallet.try_borrow_mut_lamports()? += refund_amount;
+        }
+
+        let (pool_share, creator_cut) = split_entry_fee(refund_amount, form.creator_fee_bps);
+        form.collected_amount -= pool_share;
+        form.creator_fees -= creator_cut;
+        form.entry_fees_collected -= refund_amount;
+    }
+
+    form.participant_count -= 1;
+    form.email_claim_count -= 1;
+    if ctx.accounts.response.is_some() {
+        form.response_count -= 1;
+    }
+    if form.participant_count == 0 {
+        transition(form, FormStatus::Funded)?;
+    }
+
+    // Sponsored rent goes back to the reserve, response rent is refunded via close constraint
+    let rent_sponsored = participant.rent_sponsored;
+    let wallet = ctx.accounts.wallet.to_account_info();
+    close_to_rent_payer(participant, form, wallet.clone(), rent_sponsored)?;
+    close_to_rent_payer(&ctx.accounts.email_claim, form, wallet, rent_sponsored)?;
+
+    msg!(
+        "Participant #{} withdrew: {}",
+        index,
+        ctx.accounts.wallet.key()
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawSubmission<'info> {
+    #[account(
+        mut,
+        seeds = [b"form", form.form_id.as_bytes()],
+        bump = form.bump
+    )]
+    pub form: Account<'info, Form>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"participant", form.key().as_ref(), wallet.key().as_ref()],
+        bump = participant.bump,
+        has_one = form,
+        has_one = wallet
+    )]
+    pub participant: Account<'info, Participant>,
+
+    #[account(
+        mut,
+        seeds = [b"email", form.key().as_ref(), participant.email_hash.as_ref()],
+        bump = email_claim.bump,
+        has_one = form,
+        has_one = wallet
+    )]
+    pub email_claim: Account<'info, EmailClaim>,
+
+    // Only if the participant stored answers with submit_response
+    #[account(
+        mut,
+        seeds = [b"response", form.key().as_ref(), wallet.key().as_ref()],
+        bump = response.bump,
+        has_one = form,
+        has_one = wallet,
+        close = wallet
+    )]
+    pub response: Option<Account<'info, Response>>,
+
+    // Participant holding the last index, unless the withdrawing one does
+    #[account(
+        mut,
+        seeds = [b"participant", form.key().as_ref(), last_participant.wallet.as_ref()],
+        bump = last_participant.bump,
+        has_one = form
+    )]
+    pub last_participant: Option<Account<'info, Participant>>,
+
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    // Token prize forms with an entry fee only
+    #[account(address = form.prize_mint)]
+    pub prize_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", form.key().as_ref()],
+        bump
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = form.prize_mint,
+        token::authority = wallet
+    )]
+    pub wallet_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+
+/// Pays `amount` of protocol fees to the config's treasury wallet
+/// Pass the mint and token accounts to withdraw tokens, otherwise SOL above the rent minimum
+pub fn handler(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    require!(
+        ctx.accounts.admin.key() == ctx.accounts.config.admin,
+        ErrorCode::Unauthorized
+    );
+
+    let treasury = &ctx.accounts.treasury;
+
+    if let Some(mint) = ctx.accounts.mint.as_ref() {
+        let (Some(from), Some(to), Some(token_program)) = (
+            ctx.accounts.treasury_token_account.as_ref(),
+            ctx.accounts.recipient_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return err!(ErrorCode::MissingTokenAccounts);
+        };
+
+        let treasury_seeds: &[&[u8]] = &[b"treasury", &[treasury.bump]];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                TransferChecked {
+                    from: from.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: treasury.to_account_info(),
+                },
+                &[treasury_seeds],
+            ),
+            amount,
+            mint.decimals,
+        )?;
+    } else {
+        let treasury_info = treasury.to_account_info();
+        let rent_minimum = Rent::get()?.minimum_balance(treasury_info.data_len());
+        let available = treasury_info.lamports().saturating_sub(rent_minimum);
+        require!(amount <= available, ErrorCode::InsufficientTreasury);
+
+        **treasury_info.try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.recipient.try_borrow_mut_lamports()? += amount;
+    }
+
+    msg!(
+        "Treasury withdrawn: {} to {}",
+        amount,
+        ctx.accounts.recipient.key()
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub admin: Signer<'info>,
+
+    /// CHECK: Treasury wallet from the config
+    #[account(mut, address = config.treasury)]
+    pub recipient: AccountInfo<'info>,
+
+    // Token withdrawals only
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = treasury
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = recipient
+    )]
+    pub recipient_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
@@ -1,9 +1,23 @@
-// programs/solana-form/src/lib_demo.rs
-// MVP VERSION: Simple on-chain randomness for bootcamp demo
-// initial presentation, then upgrade to Switchboard
+// programs/solanaform/src/lib.rs
+// Single program for every randomness backend.
+// Backends are compiled in through cargo features, each form picks one at initialize_form.
 
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
+
+pub mod allowlist;
+pub mod attestation;
+pub mod envelope;
+pub mod errors;
+pub mod events;
+pub mod gate;
+pub mod instructions;
+pub mod quiz;
+pub mod randomness;
+pub mod state;
+pub mod vault;
+
+pub use instructions::*;
+pub use state::*;
 
 declare_id!("FnBCbFZ1Y4rhjRuKe94HDfXcxkjL9DiU21YhCJzHRcZY");
 
@@ -11,401 +25,348 @@ declare_id!("FnBCbFZ1Y4rhjRuKe94HDfXcxkjL9DiU21YhCJzHRcZY");
 pub mod solana_form {
     use super::*;
 
+    /// Create the program config, signed by the program's upgrade authority
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        admin: Pubkey,
+        protocol_fee_bps: u16,
+        treasury: Pubkey,
+        max_prize_pool: u64,
+        max_winners: u32,
+        max_duration: i64,
+    ) -> Result<()> {
+        initialize_config::handler(
+            ctx,
+            admin,
+            protocol_fee_bps,
+            treasury,
+            max_prize_pool,
+            max_winners,
+            max_duration,
+        )
+    }
+
+    /// Admin updates the config, `admin` can hand the role over
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        admin: Pubkey,
+        protocol_fee_bps: u16,
+        treasury: Pubkey,
+        max_prize_pool: u64,
+        max_winners: u32,
+        max_duration: i64,
+    ) -> Result<()> {
+        update_config::handler(
+            ctx,
+            admin,
+            protocol_fee_bps,
+            treasury,
+            max_prize_pool,
+            max_winners,
+            max_duration,
+        )
+    }
+
+    /// Admin pauses or resumes every form instruction, winner checks and prize claims stay open
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        set_paused::handler(ctx, paused)
+    }
+
     /// Initialize a new form with prize pool
+    /// Pass `seed_commitment = hash(secret)` for the commit-reveal backend
+    /// Pass `prize_mint`, `vault` and `token_program` for an SPL Token / Token-2022 pool
+    /// `entry_fee` is charged in the same currency, `creator_fee_bps` of it goes to the authority
+    /// Pass `allowlist_root` to restrict submissions to the wallets in a Merkle allowlist
+    /// Pass `email_verifier` to only accept emails attested by that key
+    /// `questions_hash` commits to the question set, `schema_version` identifies it
+    /// Pass `answer_key_commitment` for a quiz, only correct answers can then win
+    /// Skill-ranked quizzes pay the prize schedule tiers by score instead of drawing
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize_form(
         ctx: Context<InitializeForm>,
         form_id: String,
         prize_pool: u64,
         deadline: i64,
         max_participants: u32,
+        winner_count: u32,
+        randomness_backend: RandomnessBackend,
+        seed_commitment: Option<[u8; 32]>,
+        prize_schedule: PrizeSchedule,
+        allow_overfunding: bool,
+        entry_fee: u64,
+        creator_fee_bps: u16,
+        allowlist_root: Option<[u8; 32]>,
+        email_verifier: Option<Pubkey>,
+        questions_hash: [u8; 32],
+        schema_version: u16,
+        answer_key_commitment: Option<[u8; 32]>,
+        winner_selection: WinnerSelection,
     ) -> Result<()> {
-        let form = &mut ctx.accounts.form;
-        form.authority = ctx.accounts.authority.key();
-        form.form_id = form_id;
-        form.prize_pool = prize_pool;
-        form.collected_amount = 0;
-        form.deadline = deadline;
-        form.max_participants = max_participants;
-        form.participant_count = 0;
-        form.is_active = true;
-        form.is_distributed = false;
-        form.bump = ctx.bumps.form;
-        form.random_seed = 0;
-
-        msg!("Form initialized: {}", form.form_id);
-        Ok(())
+        initialize_form::handler(
+            ctx,
+            form_id,
+            prize_pool,
+            deadline,
+            max_participants,
+            winner_count,
+            randomness_backend,
+            seed_commitment,
+            prize_schedule,
+            allow_overfunding,
+            entry_fee,
+            creator_fee_bps,
+            allowlist_root,
+            email_verifier,
+            questions_hash,
+            schema_version,
+            answer_key_commitment,
+            winner_selection,
+        )
     }
 
     /// Deposit prize pool by form creator
     pub fn deposit_prize(ctx: Context<DepositPrize>) -> Result<()> {
-        let form = &mut ctx.accounts.form;
-        
-        require!(form.is_active, ErrorCode::FormInactive);
-        require!(!form.is_distributed, ErrorCode::AlreadyDistributed);
-        require!(
-            form.collected_amount < form.prize_pool,
-            ErrorCode::PrizePoolFilled
-        );
-
-        let deposit_amount = form.prize_pool - form.collected_amount;
-
-        // Transfer SOL from authority to form PDA
-        system_program::transfer(
-            CpiContext::new(
-                ctx.accounts.system_program.to_account_info(),
-                system_program::Transfer {
-                    from: ctx.accounts.authority.to_account_info(),
-                    to: form.to_account_info(),
-                },
-            ),
-            deposit_amount,
-        )?;
-
-        form.collected_amount += deposit_amount;
-        msg!("Prize deposited: {} lamports", deposit_amount);
-        Ok(())
+        deposit_prize::handler(ctx)
+    }
+
+    /// Anyone can top up the prize pool, tracked per sponsor
+    pub fn sponsor_deposit(ctx: Context<SponsorDeposit>, amount: u64) -> Result<()> {
+        sponsor_deposit::handler(ctx, amount)
+    }
+
+    /// Create the question schema answers are validated against, before submissions open
+    /// Pass `response_encryption_key` to only accept responses encrypted to that X25519 key
+    /// Operators can create the schema, only the authority can set the encryption key
+    pub fn create_form_schema(
+        ctx: Context<CreateFormSchema>,
+        questions: Vec<Question>,
+        max_response_size: u32,
+        response_encryption_key: Option<[u8; 32]>,
+    ) -> Result<()> {
+        create_form_schema::handler(ctx, questions, max_response_size, response_encryption_key)
+    }
+
+    /// Authority sets or clears the Merkle allowlist before submissions close
+    pub fn set_allowlist(
+        ctx: Context<SetAllowlist>,
+        allowlist_root: Option<[u8; 32]>,
+    ) -> Result<()> {
+        set_allowlist::handler(ctx, allowlist_root)
+    }
+
+    /// Authority restricts submissions to token or NFT holders before submissions close
+    pub fn set_participation_gate(
+        ctx: Context<SetParticipationGate>,
+        gate: ParticipationGate,
+    ) -> Result<()> {
+        set_participation_gate::handler(ctx, gate)
+    }
+
+    /// Authority or an operator pushes the deadline back while submissions are open
+    pub fn extend_deadline(ctx: Context<ExtendDeadline>, new_deadline: i64) -> Result<()> {
+        extend_deadline::handler(ctx, new_deadline)
+    }
+
+    /// Authority proposes a new authority, which takes over once it accepts
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        propose_authority::handler(ctx, new_authority)
+    }
+
+    /// Pending authority accepts the transfer and becomes the form's authority
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        accept_authority::handler(ctx)
+    }
+
+    /// Authority grants an operator PERMISSION_* bits, 0 removes the operator
+    /// Operators may request randomness, extend the deadline or edit the form metadata
+    pub fn set_operator(
+        ctx: Context<SetOperator>,
+        operator: Pubkey,
+        permissions: u8,
+    ) -> Result<()> {
+        set_operator::handler(ctx, operator, permissions)
+    }
+
+    /// Creator funds the reserve that reimburses relayers for gasless submissions
+    pub fn fund_rent_reserve(ctx: Context<FundRentReserve>, amount: u64) -> Result<()> {
+        fund_rent_reserve::handler(ctx, amount)
     }
 
     /// Submit form and register participant
+    /// A `payer` other than `user` (a relayer) is reimbursed the rent from the form's reserve
+    /// Gated forms need the user's `gate_token_account`, and `gate_metadata` for collections
+    /// Allowlisted forms need the wallet's Merkle proof and allocation (0 if unused)
+    /// Email-verified forms need the verifier's Ed25519 instruction right before this one
+    /// `responses_hash` commits to the answers given for the form's `schema_version`
     pub fn submit_form(
         ctx: Context<SubmitForm>,
         email_hash: [u8; 32],
+        allowlist_proof: Vec<[u8; 32]>,
+        allocation: u64,
+        attestation_expiry: i64,
+        responses_hash: [u8; 32],
+        schema_version: u16,
+    ) -> Result<()> {
+        submit_form::handler(
+            ctx,
+            email_hash,
+            allowlist_proof,
+            allocation,
+            attestation_expiry,
+            responses_hash,
+            schema_version,
+        )
+    }
+
+    /// Store a participant's answers on-chain, validated against the form schema
+    pub fn submit_response(ctx: Context<SubmitResponse>, payload: ResponsePayload) -> Result<()> {
+        submit_response::handler(ctx, payload)
+    }
+
+    /// Replace a participant's answers before the deadline, resizing the account
+    pub fn update_response(ctx: Context<UpdateResponse>, payload: ResponsePayload) -> Result<()> {
+        update_response::handler(ctx, payload)
+    }
+
+    /// Quiz: authority reveals the committed answer key after the deadline
+    /// Pass the `leaderboard` account for skill-ranked forms to create it
+    pub fn reveal_answer_key(
+        ctx: Context<RevealAnswerKey>,
+        answer_key: Vec<u8>,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        reveal_answer_key::handler(ctx, answer_key, salt)
+    }
+
+    /// Quiz: prove a participant's committed answers match the key - ANYONE can call this
+    pub fn mark_eligible(
+        ctx: Context<MarkEligible>,
+        answers: Vec<u8>,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        mark_eligible::handler(ctx, answers, salt)
+    }
+
+    /// Skill-ranked: score a participant's committed answers - ANYONE can call this
+    pub fn record_score(
+        ctx: Context<RecordScore>,
+        answers: Vec<u8>,
+        salt: [u8; 32],
     ) -> Result<()> {
-        let form = &mut ctx.accounts.form;
-        let participant = &mut ctx.accounts.participant;
-        let clock = Clock::get()?;
-
-        require!(form.is_active, ErrorCode::FormInactive);
-        require!(!form.is_distributed, ErrorCode::AlreadyDistributed);
-        require!(
-            clock.unix_timestamp < form.deadline,
-            ErrorCode::DeadlinePassed
-        );
-        require!(
-            form.participant_count < form.max_participants,
-            ErrorCode::MaxParticipantsReached
-        );
-
-        participant.wallet = ctx.accounts.user.key();
-        participant.form = form.key();
-        participant.email_hash = email_hash;
-        participant.timestamp = clock.unix_timestamp;
-        participant.participant_index = form.participant_count;
-        participant.is_winner = false;
-        participant.claimed = false;
-        participant.bump = ctx.bumps.participant;
-
-        form.participant_count += 1;
-
-        msg!("Participant registered: {}", ctx.accounts.user.key());
-        Ok(())
-    }
-
-    /// Generate random seed and distribute prizes (simplified for demo)
+        record_score::handler(ctx, answers, salt)
+    }
+
+    /// Leave a form before the deadline, entry fee and rent go back to the participant
+    /// Pass the participant holding the last index as `last_participant`, it takes the freed one
+    /// When the last participant leaves the form goes back to Funded
+    pub fn withdraw_submission(ctx: Context<WithdrawSubmission>) -> Result<()> {
+        withdraw_submission::handler(ctx)
+    }
+
+    /// Slot-hash backend: derive the seed from the latest slot hash
+    #[cfg(feature = "slot-hash")]
     pub fn distribute_prizes(ctx: Context<DistributePrizes>) -> Result<()> {
-        let form = &mut ctx.accounts.form;
-        let clock = Clock::get()?;
-
-        require!(form.is_active, ErrorCode::FormInactive);
-        require!(!form.is_distributed, ErrorCode::AlreadyDistributed);
-        require!(
-            clock.unix_timestamp >= form.deadline,
-            ErrorCode::DeadlineNotReached
-        );
-        require!(
-            ctx.accounts.authority.key() == form.authority,
-            ErrorCode::Unauthorized
-        );
-        require!(form.participant_count > 0, ErrorCode::NoParticipants);
-
-        // Generate pseudo-random seed from multiple sources
-        // Note: This is deterministic and verifiable, but not cryptographically secure
-        // Good enough for MVP demo, upgrade to Switchboard for production
-        let slot = clock.slot;
-        let timestamp = clock.unix_timestamp;
-        
-        // Combine multiple entropy sources
-        let mut seed: u64 = slot;
-        
-        // Mix in timestamp
-        seed = seed.wrapping_mul(31).wrapping_add(timestamp as u64);
-        
-        // Mix in form ID bytes
-        for byte in form.form_id.as_bytes() {
-            seed = seed.wrapping_mul(31).wrapping_add(*byte as u64);
-        }
-        
-        // Mix in participant count
-        seed = seed.wrapping_mul(31).wrapping_add(form.participant_count as u64);
-
-        form.random_seed = seed;
-        form.is_distributed = true;
-        form.is_active = false;
-
-        msg!(
-            "Prizes distributed. Random seed: {}, Winners: {}",
-            seed,
-            form.participant_count.min(10)
-        );
-        Ok(())
-    }
-
-    /// Check if participant is a winner and mark them
-    /// Anyone can call this to check/claim their winner status
-    pub fn check_and_claim_prize(ctx: Context<CheckAndClaimPrize>) -> Result<()> {
-        let form = &ctx.accounts.form;
-        let participant = &mut ctx.accounts.participant;
-
-        require!(form.is_distributed, ErrorCode::NotDistributed);
-        require!(!participant.claimed, ErrorCode::AlreadyClaimed);
-
-        // Determine if this participant is a winner using the random seed
-        let winners_count = form.participant_count.min(10);
-        let is_winner = is_participant_winner(
-            form.random_seed,
-            participant.participant_index,
-            form.participant_count,
-            winners_count,
-        );
-
-        require!(is_winner, ErrorCode::NotAWinner);
-
-        // Calculate prize amount
-        let prize_amount = form.collected_amount / winners_count as u64;
-
-        // Transfer from form PDA to winner
-        **form.to_account_info().try_borrow_mut_lamports()? -= prize_amount;
-        **ctx.accounts.winner.try_borrow_mut_lamports()? += prize_amount;
-
-        participant.is_winner = true;
-        participant.claimed = true;
-
-        msg!(
-            "Prize claimed: {} lamports to {}",
-            prize_amount,
-            ctx.accounts.winner.key()
-        );
-        Ok(())
+        distribute_prizes::handler(ctx)
     }
 
-    /// Close form and refund remaining funds to authority
-    pub fn close_form(ctx: Context<CloseForm>) -> Result<()> {
-        let form = &ctx.accounts.form;
-        
-        require!(
-            ctx.accounts.authority.key() == form.authority,
-            ErrorCode::Unauthorized
-        );
-        require!(
-            form.participant_count == 0 || form.is_distributed,
-            ErrorCode::CannotClose
-        );
-
-        msg!("Form closed and refunded");
-        Ok(())
+    /// Switchboard backend, step 1: authority or an operator requests randomness
+    /// Commit the Switchboard randomness account in the previous slot, older accounts are rejected
+    #[cfg(feature = "switchboard")]
+    pub fn request_randomness(ctx: Context<RequestRandomness>) -> Result<()> {
+        request_randomness::handler(ctx)
     }
-}
 
-// ============ HELPER FUNCTIONS ============
-
-/// Deterministic winner selection using random seed
-/// This ensures the same seed always produces the same winners
-fn is_participant_winner(
-    seed: u64,
-    participant_index: u32,
-    total_participants: u32,
-    winners_count: u32,
-) -> bool {
-    // Simple selection: First N participants after shuffling
-    let shuffled_index = (participant_index as u64).wrapping_mul(seed) % (total_participants as u64);
-    shuffled_index < winners_count as u64
-}
+    /// Switchboard backend, step 2: settle randomness from the oracle
+    #[cfg(feature = "switchboard")]
+    pub fn settle_randomness(ctx: Context<SettleRandomness>) -> Result<()> {
+        settle_randomness::handler(ctx)
+    }
 
-// ============ CONTEXTS ============
-
-#[derive(Accounts)]
-#[instruction(form_id: String)]
-pub struct InitializeForm<'info> {
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + Form::INIT_SPACE,
-        seeds = [b"form", form_id.as_bytes()],
-        bump
-    )]
-    pub form: Account<'info, Form>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
-}
+    /// Switchboard backend, step 2B: emergency fallback if the oracle fails
+    #[cfg(feature = "switchboard")]
+    pub fn emergency_fallback(ctx: Context<EmergencyFallback>) -> Result<()> {
+        emergency_fallback::handler(ctx)
+    }
 
-#[derive(Accounts)]
-pub struct DepositPrize<'info> {
-    #[account(
-        mut,
-        seeds = [b"form", form.form_id.as_bytes()],
-        bump = form.bump,
-        has_one = authority
-    )]
-    pub form: Account<'info, Form>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
-}
+    /// Commit-reveal backend, step 1: lock the future slot mixed into the seed
+    #[cfg(feature = "commit-reveal")]
+    pub fn request_reveal(ctx: Context<RequestReveal>) -> Result<()> {
+        request_reveal::handler(ctx)
+    }
 
-#[derive(Accounts)]
-pub struct SubmitForm<'info> {
-    #[account(
-        mut,
-        seeds = [b"form", form.form_id.as_bytes()],
-        bump = form.bump
-    )]
-    pub form: Account<'info, Form>,
-    
-    #[account(
-        init,
-        payer = user,
-        space = 8 + Participant::INIT_SPACE,
-        seeds = [b"participant", form.key().as_ref(), user.key().as_ref()],
-        bump
-    )]
-    pub participant: Account<'info, Participant>,
-    
-    #[account(mut)]
-    pub user: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
-}
+    /// Commit-reveal backend, step 2: authority reveals the committed secret
+    #[cfg(feature = "commit-reveal")]
+    pub fn reveal_seed(ctx: Context<RevealSeed>, secret: [u8; 32]) -> Result<()> {
+        reveal_seed::handler(ctx, secret)
+    }
 
-#[derive(Accounts)]
-pub struct DistributePrizes<'info> {
-    #[account(
-        mut,
-        seeds = [b"form", form.form_id.as_bytes()],
-        bump = form.bump,
-        has_one = authority
-    )]
-    pub form: Account<'info, Form>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-}
+    /// Commit-reveal backend, step 2B: authority never revealed, pool is forfeited
+    #[cfg(feature = "commit-reveal")]
+    pub fn forfeit_reveal(ctx: Context<ForfeitReveal>) -> Result<()> {
+        forfeit_reveal::handler(ctx)
+    }
 
-#[derive(Accounts)]
-pub struct CheckAndClaimPrize<'info> {
-    #[account(
-        mut,
-        seeds = [b"form", form.form_id.as_bytes()],
-        bump = form.bump
-    )]
-    pub form: Account<'info, Form>,
-    
-    #[account(
-        mut,
-        seeds = [b"participant", form.key().as_ref(), winner.key().as_ref()],
-        bump = participant.bump,
-        has_one = form
-    )]
-    pub participant: Account<'info, Participant>,
-    
-    /// CHECK: Winner receiving prize (must match participant.wallet)
-    #[account(
-        mut,
-        constraint = winner.key() == participant.wallet
-    )]
-    pub winner: AccountInfo<'info>,
-    
-    pub system_program: Program<'info, System>,
-}
+    /// Admin-declared backend: authority declares each winner
+    #[cfg(feature = "admin-declared")]
+    pub fn declare_winner(ctx: Context<DeclareWinner>) -> Result<()> {
+        declare_winner::handler(ctx)
+    }
 
-#[derive(Accounts)]
-pub struct CloseForm<'info> {
-    #[account(
-        mut,
-        seeds = [b"form", form.form_id.as_bytes()],
-        bump = form.bump,
-        has_one = authority,
-        close = authority
-    )]
-    pub form: Account<'info, Form>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-}
+    /// Winner lookup - ANYONE can call this, claim_prize does not depend on it
+    pub fn check_winner_status(ctx: Context<CheckWinnerStatus>) -> Result<()> {
+        check_winner_status::handler(ctx)
+    }
 
-// ============ ACCOUNTS ============
-
-#[account]
-#[derive(InitSpace)]
-pub struct Form {
-    pub authority: Pubkey,           // 32
-    #[max_len(50)]
-    pub form_id: String,             // 4 + 50
-    pub prize_pool: u64,             // 8
-    pub collected_amount: u64,       // 8
-    pub deadline: i64,               // 8
-    pub max_participants: u32,       // 4
-    pub participant_count: u32,      // 4
-    pub is_active: bool,             // 1
-    pub is_distributed: bool,        // 1
-    pub bump: u8,                    // 1
-    pub random_seed: u64,            // 8
-}
+    /// Winner claims their prize, minus the protocol fee snapshotted when the form was created
+    /// Token forms with a fee need a `treasury_token_account` of the prize mint
+    pub fn claim_prize(ctx: Context<ClaimPrize>) -> Result<()> {
+        claim_prize::handler(ctx)
+    }
 
-#[account]
-#[derive(InitSpace)]
-pub struct Participant {
-    pub wallet: Pubkey,              // 32
-    pub form: Pubkey,                // 32
-    pub email_hash: [u8; 32],        // 32
-    pub timestamp: i64,              // 8
-    pub participant_index: u32,      // 4 (for winner selection)
-    pub is_winner: bool,             // 1
-    pub claimed: bool,               // 1
-    pub bump: u8,                    // 1
-}
+    /// Return unclaimed prizes to the authority once the claim window has ended
+    pub fn sweep_unclaimed(ctx: Context<SweepUnclaimed>) -> Result<()> {
+        sweep_unclaimed::handler(ctx)
+    }
+
+    /// Cancel the form before randomness is requested (or a quiz's answer key is revealed)
+    /// and refund the pool to the authority
+    pub fn cancel_form(ctx: Context<CancelForm>) -> Result<()> {
+        cancel_form::handler(ctx)
+    }
+
+    /// Refund a sponsor of a cancelled form and close their sponsorship
+    pub fn refund_sponsor(ctx: Context<RefundSponsor>) -> Result<()> {
+        refund_sponsor::handler(ctx)
+    }
+
+    /// Authority withdraws its cut of the entry fees once the draw has started
+    pub fn withdraw_creator_fees(ctx: Context<WithdrawCreatorFees>) -> Result<()> {
+        withdraw_creator_fees::handler(ctx)
+    }
+
+    /// Admin withdraws protocol fees from the treasury to the config's treasury wallet
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        withdraw_treasury::handler(ctx, amount)
+    }
 
-// ============ ERRORS ============
-
-#[error_code]
-pub enum ErrorCode {
-    #[msg("Form is not active")]
-    FormInactive,
-    
-    #[msg("Prizes already distributed")]
-    AlreadyDistributed,
-    
-    #[msg("Prize pool already filled")]
-    PrizePoolFilled,
-    
-    #[msg("Deadline has passed")]
-    DeadlinePassed,
-    
-    #[msg("Deadline has not been reached yet")]
-    DeadlineNotReached,
-    
-    #[msg("Maximum participants reached")]
-    MaxParticipantsReached,
-    
-    #[msg("Unauthorized")]
-    Unauthorized,
-    
-    #[msg("No participants to distribute to")]
-    NoParticipants,
-    
-    #[msg("Prizes not distributed yet")]
-    NotDistributed,
-    
-    #[msg("Prize already claimed")]
-    AlreadyClaimed,
-    
-    #[msg("Cannot close form with active participants")]
-    CannotClose,
-    
-    #[msg("Participant is not a winner")]
-    NotAWinner,
-}
\ No newline at end of file
+    /// Close a participant of a cancelled form, entry fee and rent go back to the participant
+    /// Pass the participant's `response`, if any, to close it too
+    pub fn close_participant(ctx: Context<CloseParticipant>) -> Result<()> {
+        close_participant::handler(ctx)
+    }
+
+    /// Close an email claim once submissions are closed, rent goes back to the wallet
+    pub fn close_email_claim(ctx: Context<CloseEmailClaim>, email_hash: [u8; 32]) -> Result<()> {
+        close_email_claim::handler(ctx, email_hash)
+    }
+
+    /// Close a response once the form is settled or cancelled, rent goes back to the wallet
+    pub fn close_response(ctx: Context<CloseResponse>) -> Result<()> {
+        close_response::handler(ctx)
+    }
+
+    /// Close form and refund remaining funds to authority
+    pub fn close_form(ctx: Context<CloseForm>) -> Result<()> {
+        close_form::handler(ctx)
+    }
+}
@@ -1,641 +0,0 @@
-// programs/solana-form/src/lib_switchboard_secured.rs
-// PRODUCTION VERSION: Switchboard VRF with security fixes
-// Fixes: Authority manipulation, Oracle downtime, Deterministic winner selection
-
-use anchor_lang::prelude::*;
-use anchor_lang::system_program;
-use switchboard_on_demand::accounts::RandomnessAccountData;
-
-declare_id!("YourProgramIDHere111111111111111111111111111");
-
-// Constants
-const ORACLE_TIMEOUT_SECONDS: i64 = 604800; // 7 days
-const MAX_WINNERS: u32 = 10;
-
-#[program]
-pub mod solana_form {
-    use super::*;
-
-    /// Initialize a new form with prize pool
-    pub fn initialize_form(
-        ctx: Context<InitializeForm>,
-        form_id: String,
-        prize_pool: u64,
-        deadline: i64,
-        max_participants: u32,
-    ) -> Result<()> {
-        let form = &mut ctx.accounts.form;
-        form.authority = ctx.accounts.authority.key();
-        form.form_id = form_id;
-        form.prize_pool = prize_pool;
-        form.collected_amount = 0;
-        form.deadline = deadline;
-        form.max_participants = max_participants;
-        form.participant_count = 0;
-        form.is_active = true;
-        form.is_distributed = false;
-        form.randomness_requested = false;
-        form.randomness_settled = false;
-        form.uses_fallback = false;
-        form.bump = ctx.bumps.form;
-        form.randomness_account = Pubkey::default();
-        form.random_value = [0u8; 32];
-
-        msg!("Form initialized: {}", form.form_id);
-        Ok(())
-    }
-
-    /// Deposit prize pool by form creator
-    pub fn deposit_prize(ctx: Context<DepositPrize>) -> Result<()> {
-        let form = &mut ctx.accounts.form;
-        
-        require!(form.is_active, ErrorCode::FormInactive);
-        require!(!form.is_distributed, ErrorCode::AlreadyDistributed);
-        require!(
-            form.collected_amount < form.prize_pool,
-            ErrorCode::PrizePoolFilled
-        );
-
-        let deposit_amount = form.prize_pool - form.collected_amount;
-
-        system_program::transfer(
-            CpiContext::new(
-                ctx.accounts.system_program.to_account_info(),
-                system_program::Transfer {
-                    from: ctx.accounts.authority.to_account_info(),
-                    to: ctx.accounts.form.to_account_info(),
-                },
-            ),
-            deposit_amount,
-        )?;
-
-        form.collected_amount += deposit_amount;
-        msg!("Prize deposited: {} lamports", deposit_amount);
-        Ok(())
-    }
-
-    /// Submit form and register participant
-    pub fn submit_form(
-        ctx: Context<SubmitForm>,
-        email_hash: [u8; 32],
-    ) -> Result<()> {
-        let form = &mut ctx.accounts.form;
-        let participant = &mut ctx.accounts.participant;
-        let clock = Clock::get()?;
-
-        require!(form.is_active, ErrorCode::FormInactive);
-        require!(!form.is_distributed, ErrorCode::AlreadyDistributed);
-        require!(
-            clock.unix_timestamp < form.deadline,
-            ErrorCode::DeadlinePassed
-        );
-        require!(
-            form.participant_count < form.max_participants,
-            ErrorCode::MaxParticipantsReached
-        );
-
-        participant.wallet = ctx.accounts.user.key();
-        participant.form = form.key();
-        participant.email_hash = email_hash;
-        participant.timestamp = clock.unix_timestamp;
-        participant.participant_index = form.participant_count; // IMPORTANT: Sequential index
-        participant.is_winner = false;
-        participant.claimed = false;
-        participant.bump = ctx.bumps.participant;
-
-        form.participant_count += 1;
-
-        msg!("Participant #{} registered: {}", participant.participant_index, ctx.accounts.user.key());
-        Ok(())
-    }
-
-    /// Step 1: Authority requests randomness from Switchboard
-    pub fn request_randomness(ctx: Context<RequestRandomness>) -> Result<()> {
-        let form = &mut ctx.accounts.form;
-        let clock = Clock::get()?;
-     
-        require!(form.is_active, ErrorCode::FormInactive);
-        require!(
-            clock.unix_timestamp >= form.deadline,
-            ErrorCode::DeadlineNotReached
-        );
-        require!(!form.is_distributed, ErrorCode::AlreadyDistributed);
-        require!(!form.randomness_requested, ErrorCode::RandomnessAlreadyRequested);
-        require!(
-            ctx.accounts.authority.key() == form.authority,
-            ErrorCode::Unauthorized
-        );
-        require!(form.participant_count > 0, ErrorCode::NoParticipants);
-    
-        form.randomness_account = ctx.accounts.randomness_account.key();
-        form.randomness_requested = true;
-        form.randomness_request_time = clock.unix_timestamp;
-    
-        msg!("Randomness requested at timestamp: {}", clock.unix_timestamp);
-        Ok(())
-    }
-
-    /// Step 2: Settle randomness from Switchboard oracle
-    pub fn settle_randomness(ctx: Context<SettleRandomness>) -> Result<()> {
-        let form = &mut ctx.accounts.form;
-        let clock = Clock::get()?;
-    
-        require!(form.randomness_requested, ErrorCode::RandomnessNotRequested);
-        require!(!form.randomness_settled, ErrorCode::RandomnessAlreadySettled);
-        require!(!form.is_distributed, ErrorCode::AlreadyDistributed);
-        require!(
-            ctx.accounts.authority.key() == form.authority,
-            ErrorCode::Unauthorized
-        );
-
-        // Parse the randomness account data
-        let randomness_data =
-            RandomnessAccountData::parse(&ctx.accounts.randomness_account.data.borrow())
-                .map_err(|_| error!(ErrorCode::SwitchboardError))?;
-    
-        // Get the random value
-        let random_value = randomness_data
-            .get_value(&clock)
-            .map_err(|_| error!(ErrorCode::RandomnessNotResolved))?;
-    
-        form.random_value = random_value;
-        form.randomness_settled = true;
-        form.is_distributed = true;
-        form.is_active = false;
-    
-        msg!("Randomness settled. Value: {:?}", random_value);
-        Ok(())
-    }
-
-    /// Step 2B: Emergency fallback if Switchboard fails (after 7 days)
-    /// FIX: Issue 2 - Oracle Downtime
-    pub fn emergency_fallback(ctx: Context<EmergencyFallback>) -> Result<()> {
-        let form = &mut ctx.accounts.form;
-        let clock = Clock::get()?;
-
-        require!(form.randomness_requested, ErrorCode::RandomnessNotRequested);
-        require!(!form.randomness_settled, ErrorCode::RandomnessAlreadySettled);
-        require!(
-            clock.unix_timestamp >= form.randomness_request_time + ORACLE_TIMEOUT_SECONDS,
-            ErrorCode::TooEarlyForFallback
-        );
-        require!(
-            ctx.accounts.authority.key() == form.authority,
-            ErrorCode::Unauthorized
-        );
-
-        // Generate fallback randomness from on-chain sources
-        // Combine multiple entropy sources
-        let slot = clock.slot;
-        let timestamp = clock.unix_timestamp;
-        
-        // Use slot hashes if available (Solana's recent blockhashes)
-        let slot_hash = match ctx.accounts.slot_hashes.as_ref() {
-            Some(slot_hashes_account) => {
-                // Try to get a recent slot hash
-                let data = slot_hashes_account.data.borrow();
-                if data.len() >= 32 {
-                    let mut hash = [0u8; 32];
-                    hash.copy_from_slice(&data[0..32]);
-                    hash
-                } else {
-                    // Fallback to deterministic generation
-                    generate_fallback_seed(slot, timestamp, &form.form_id)
-                }
-            }
-            None => {
-                // No slot hashes available, use deterministic fallback
-                generate_fallback_seed(slot, timestamp, &form.form_id)
-            }
-        };
-
-        form.random_value = slot_hash;
-        form.randomness_settled = true;
-        form.is_distributed = true;
-        form.is_active = false;
-        form.uses_fallback = true; // Mark that fallback was used
-
-        msg!("Emergency fallback activated. Using slot-based randomness.");
-        msg!("⚠️ WARNING: Fallback randomness is less secure than Switchboard VRF");
-        Ok(())
-    }
-
-    /// Step 3: Deterministic winner check - ANYONE can call this
-    /// FIX: Issue 1 - Authority Manipulation
-    /// Winner selection is now fully deterministic and on-chain
-    pub fn check_winner_status(ctx: Context<CheckWinnerStatus>) -> Result<()> {
-        let form = &ctx.accounts.form;
-        let participant = &mut ctx.accounts.participant;
-
-        require!(form.randomness_settled, ErrorCode::RandomnessNotSettled);
-        require!(form.is_distributed, ErrorCode::NotDistributed);
-
-        // Calculate if this participant is a winner using on-chain logic
-        let is_winner = calculate_winner_deterministic(
-            &form.random_value,
-            participant.participant_index,
-            form.participant_count,
-            MAX_WINNERS,
-        );
-
-        participant.is_winner = is_winner;
-
-        if is_winner {
-            msg!("🎉 Participant #{} is a WINNER!", participant.participant_index);
-        } else {
-            msg!("Participant #{} did not win this time", participant.participant_index);
-        }
-
-        Ok(())
-    }
-
-    /// Step 4: Winner claims their prize (only if is_winner = true)
-    pub fn claim_prize(ctx: Context<ClaimPrize>) -> Result<()> {
-        let form = &ctx.accounts.form;
-        let participant = &mut ctx.accounts.participant;
-
-        require!(form.is_distributed, ErrorCode::NotDistributed);
-        require!(participant.is_winner, ErrorCode::NotAWinner);
-        require!(!participant.claimed, ErrorCode::AlreadyClaimed);
-
-        // Calculate prize amount
-        let winners_count = form.participant_count.min(MAX_WINNERS);
-        let prize_amount = form.collected_amount / winners_count as u64;
-
-        // Transfer from form PDA to winner
-        let form_seeds = &[
-            b"form",
-            form.form_id.as_bytes(),
-            &[form.bump],
-        ];
-        let signer_seeds = &[&form_seeds[..]];
-
-        **form.to_account_info().try_borrow_mut_lamports()? -= prize_amount;
-        **ctx.accounts.winner.try_borrow_mut_lamports()? += prize_amount;
-
-        participant.claimed = true;
-
-        msg!(
-            "Prize claimed: {} lamports to {}",
-            prize_amount,
-            ctx.accounts.winner.key()
-        );
-        Ok(())
-    }
-
-    /// Close form and refund remaining funds to authority
-    pub fn close_form(ctx: Context<CloseForm>) -> Result<()> {
-        let form = &ctx.accounts.form;
-        
-        require!(
-            ctx.accounts.authority.key() == form.authority,
-            ErrorCode::Unauthorized
-        );
-        require!(
-            form.participant_count == 0 || form.is_distributed,
-            ErrorCode::CannotClose
-        );
-
-        msg!("Form closed and refunded");
-        Ok(())
-    }
-}
-
-// ============ HELPER FUNCTIONS ============
-
-/// Deterministic winner selection using cryptographic randomness
-/// This ensures the same random_value always produces the same winners
-/// NO AUTHORITY CAN MANIPULATE THIS - it's pure math
-fn calculate_winner_deterministic(
-    random_value: &[u8; 32],
-    participant_index: u32,
-    total_participants: u32,
-    max_winners: u32,
-) -> bool {
-    let winners_count = total_participants.min(max_winners);
-    
-    // Use Fisher-Yates shuffle logic with the random seed
-    // Create a deterministic pseudo-random number generator from the seed
-    let mut seed = u64::from_le_bytes([
-        random_value[0], random_value[1], random_value[2], random_value[3],
-        random_value[4], random_value[5], random_value[6], random_value[7],
-    ]);
-
-    // Simple LCG (Linear Congruential Generator) for deterministic randomness
-    // This ensures everyone gets the same result when checking
-    for i in 0..total_participants {
-        seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
-        let random_index = (seed % (total_participants as u64)) as u32;
-        
-        // Check if this shuffled position is a winner
-        if random_index < winners_count && i == participant_index {
-            return true;
-        }
-        
-        // Alternative: Select first N after shuffling
-        if i < winners_count {
-            let selected = (seed % (total_participants as u64)) as u32;
-            if selected == participant_index {
-                return true;
-            }
-        }
-    }
-    
-    // Simpler approach: Hash participant index with random value
-    let mut combined = seed;
-    combined = combined.wrapping_mul(31).wrapping_add(participant_index as u64);
-    
-    // Select top N participants by their hash value
-    let threshold = (winners_count as u64 * u64::MAX) / (total_participants as u64);
-    combined < threshold
-}
-
-/// Generate fallback seed from on-chain data
-fn generate_fallback_seed(slot: u64, timestamp: i64, form_id: &str) -> [u8; 32] {
-    let mut seed = slot;
-    seed = seed.wrapping_mul(31).wrapping_add(timestamp as u64);
-    
-    for byte in form_id.as_bytes() {
-        seed = seed.wrapping_mul(31).wrapping_add(*byte as u64);
-    }
-    
-    // Create 32 bytes from the seed
-    let mut result = [0u8; 32];
-    for i in 0..4 {
-        let bytes = seed.wrapping_mul(i as u64 + 1).to_le_bytes();
-        result[i * 8..(i + 1) * 8].copy_from_slice(&bytes);
-    }
-    result
-}
-
-// ============ CONTEXTS ============
-
-#[derive(Accounts)]
-#[instruction(form_id: String)]
-pub struct InitializeForm<'info> {
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + Form::INIT_SPACE,
-        seeds = [b"form", form_id.as_bytes()],
-        bump
-    )]
-    pub form: Account<'info, Form>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
-}
-
-#[derive(Accounts)]
-pub struct DepositPrize<'info> {
-    #[account(
-        mut,
-        seeds = [b"form", form.form_id.as_bytes()],
-        bump = form.bump,
-        has_one = authority
-    )]
-    pub form: Account<'info, Form>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
-}
-
-#[derive(Accounts)]
-pub struct SubmitForm<'info> {
-    #[account(
-        mut,
-        seeds = [b"form", form.form_id.as_bytes()],
-        bump = form.bump
-    )]
-    pub form: Account<'info, Form>,
-    
-    #[account(
-        init,
-        payer = user,
-        space = 8 + Participant::INIT_SPACE,
-        seeds = [b"participant", form.key().as_ref(), user.key().as_ref()],
-        bump
-    )]
-    pub participant: Account<'info, Participant>,
-    
-    #[account(mut)]
-    pub user: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
-}
-
-#[derive(Accounts)]
-pub struct RequestRandomness<'info> {
-    #[account(
-        mut,
-        seeds = [b"form", form.form_id.as_bytes()],
-        bump = form.bump,
-        has_one = authority
-    )]
-    pub form: Account<'info, Form>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    /// CHECK: The Switchboard randomness account
-    pub randomness_account: AccountInfo<'info>,
-}
- 
-#[derive(Accounts)]
-pub struct SettleRandomness<'info> {
-    #[account(
-        mut,
-        seeds = [b"form", form.form_id.as_bytes()],
-        bump = form.bump,
-        has_one = authority
-    )]
-    pub form: Account<'info, Form>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    /// CHECK: The Switchboard randomness account
-    #[account(constraint = randomness_account.key() == form.randomness_account)]
-    pub randomness_account: AccountInfo<'info>,
-}
-
-#[derive(Accounts)]
-pub struct EmergencyFallback<'info> {
-    #[account(
-        mut,
-        seeds = [b"form", form.form_id.as_bytes()],
-        bump = form.bump,
-        has_one = authority
-    )]
-    pub form: Account<'info, Form>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    /// CHECK: Optional slot hashes sysvar for entropy
-    pub slot_hashes: Option<AccountInfo<'info>>,
-}
-
-#[derive(Accounts)]
-pub struct CheckWinnerStatus<'info> {
-    #[account(
-        seeds = [b"form", form.form_id.as_bytes()],
-        bump = form.bump
-    )]
-    pub form: Account<'info, Form>,
-    
-    #[account(
-        mut,
-        seeds = [b"participant", form.key().as_ref(), participant.wallet.as_ref()],
-        bump = participant.bump,
-        has_one = form
-    )]
-    pub participant: Account<'info, Participant>,
-    
-    // NOTE: No signer required! Anyone can check any participant's status
-}
-
-#[derive(Accounts)]
-pub struct ClaimPrize<'info> {
-    #[account(
-        mut,
-        seeds = [b"form", form.form_id.as_bytes()],
-        bump = form.bump
-    )]
-    pub form: Account<'info, Form>,
-    
-    #[account(
-        mut,
-        seeds = [b"participant", form.key().as_ref(), winner.key().as_ref()],
-        bump = participant.bump,
-        has_one = form
-    )]
-    pub participant: Account<'info, Participant>,
-    
-    /// CHECK: Winner receiving prize
-    #[account(
-        mut,
-        constraint = winner.key() == participant.wallet
-    )]
-    pub winner: AccountInfo<'info>,
-    
-    pub system_program: Program<'info, System>,
-}
-
-#[derive(Accounts)]
-pub struct CloseForm<'info> {
-    #[account(
-        mut,
-        seeds = [b"form", form.form_id.as_bytes()],
-        bump = form.bump,
-        has_one = authority,
-        close = authority
-    )]
-    pub form: Account<'info, Form>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-}
-
-// ============ ACCOUNTS ============
-
-#[account]
-#[derive(InitSpace)]
-pub struct Form {
-    pub authority: Pubkey,              // 32
-    #[max_len(50)]
-    pub form_id: String,                // 4 + 50
-    pub prize_pool: u64,                // 8
-    pub collected_amount: u64,          // 8
-    pub deadline: i64,                  // 8
-    pub max_participants: u32,          // 4
-    pub participant_count: u32,         // 4
-    pub is_active: bool,                // 1
-    pub is_distributed: bool,           // 1
-    pub randomness_requested: bool,     // 1
-    pub randomness_settled: bool,       // 1
-    pub uses_fallback: bool,            // 1 (marks if emergency fallback was used)
-    pub bump: u8,                       // 1
-    pub randomness_account: Pubkey,     // 32
-    pub random_value: [u8; 32],         // 32
-    pub randomness_request_time: i64,   // 8 (for timeout check)
-}
-
-#[account]
-#[derive(InitSpace)]
-pub struct Participant {
-    pub wallet: Pubkey,              // 32
-    pub form: Pubkey,                // 32
-    pub email_hash: [u8; 32],        // 32
-    pub timestamp: i64,              // 8
-    pub participant_index: u32,      // 4 (for deterministic winner selection)
-    pub is_winner: bool,             // 1
-    pub claimed: bool,               // 1
-    pub bump: u8,                    // 1
-}
-
-// ============ ERRORS ============
-
-#[error_code]
-pub enum ErrorCode {
-    #[msg("Form is not active")]
-    FormInactive,
-    
-    #[msg("Prizes already distributed")]
-    AlreadyDistributed,
-    
-    #[msg("Prize pool already filled")]
-    PrizePoolFilled,
-    
-    #[msg("Deadline has passed")]
-    DeadlinePassed,
-    
-    #[msg("Deadline has not been reached yet")]
-    DeadlineNotReached,
-    
-    #[msg("Maximum participants reached")]
-    MaxParticipantsReached,
-    
-    #[msg("Unauthorized")]
-    Unauthorized,
-    
-    #[msg("No participants to distribute to")]
-    NoParticipants,
-    
-    #[msg("Prizes not distributed yet")]
-    NotDistributed,
-    
-    #[msg("Prize already claimed")]
-    AlreadyClaimed,
-    
-    #[msg("Cannot close form with active participants")]
-    CannotClose,
-
-    #[msg("Switchboard account parsing failed")]
-    SwitchboardError,
-
-    #[msg("Randomness not yet resolved")]
-    RandomnessNotResolved,
-    
-    #[msg("Participant is not a winner")]
-    NotAWinner,
-
-    #[msg("Randomness already requested")]
-    RandomnessAlreadyRequested,
-
-    #[msg("Randomness not yet requested")]
-    RandomnessNotRequested,
-
-    #[msg("Randomness already settled")]
-    RandomnessAlreadySettled,
-
-    #[msg("Randomness not settled yet")]
-    RandomnessNotSettled,
-
-    #[msg("Too early for emergency fallback (wait 7 days after request)")]
-    TooEarlyForFallback,
-}
\ No newline at end of file
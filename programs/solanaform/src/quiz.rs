@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+// ============ QUIZ COMMITMENTS ============
+// Answers are one choice index per question. The creator commits to the answer key at
+// initialize_form, participants commit to their answers as Participant.responses_hash.
+// Participant commitments include the wallet so a commitment can't be copied.
+
+/// Form.answer_key_commitment for `answer_key`
+pub fn answer_key_commitment(answer_key: &[u8], salt: &[u8; 32]) -> [u8; 32] {
+    hashv(&[b"solana_form:answer_key".as_ref(), salt, answer_key]).to_bytes()
+}
+
+/// Participant.responses_hash of a quiz form
+pub fn quiz_answers_commitment(wallet: &Pubkey, answers: &[u8], salt: &[u8; 32]) -> [u8; 32] {
+    hashv(&[
+        b"solana_form:quiz_answers".as_ref(),
+        wallet.as_ref(),
+        salt,
+        answers,
+    ])
+    .to_bytes()
+}
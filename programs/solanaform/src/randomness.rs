@@ -0,0 +1,356 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+use crate::errors::ErrorCode;
+use crate::state::{Form, Leaderboard, LeaderboardEntry, WinnerSelection};
+
+// Constants
+pub const ORACLE_TIMEOUT_SECONDS: i64 = 604800; // 7 days
+pub const REVEAL_SLOT_DELAY: u64 = 10; // slots between request_reveal and the slot hash used
+pub const SLOT_HASHES_WINDOW: u64 = 512; // slots kept by the SlotHashes sysvar
+
+// ============ WINNER SELECTION ============
+
+/// Deterministic winner selection using cryptographic randomness
+/// This ensures the same random_value always produces the same winners
+///
+/// Runs a partial Fisher-Yates shuffle over the participant indices and
+/// returns exactly `min(total_participants, max_winners)` distinct indices,
+/// ordered by draw (index 0 is the first winner drawn).
+pub fn select_winners(
+    random_value: &[u8; 32],
+    total_participants: u32,
+    max_winners: u32,
+) -> Vec<u32> {
+    let winners_count = total_participants.min(max_winners);
+    let mut stream = RandomStream::new(random_value);
+
+    // Only the swapped positions are stored, every other position i still
+    // holds its own index. Keeps the shuffle O(winners) instead of O(participants).
+    let mut swapped: Vec<(u32, u32)> = Vec::with_capacity(2 * winners_count as usize);
+    let value_at = |swapped: &Vec<(u32, u32)>, position: u32| -> u32 {
+        swapped
+            .iter()
+            .find(|(p, _)| *p == position)
+            .map(|(_, v)| *v)
+            .unwrap_or(position)
+    };
+    let set_at = |swapped: &mut Vec<(u32, u32)>, position: u32, value: u32| {
+        match swapped.iter_mut().find(|(p, _)| *p == position) {
+            Some(entry) => entry.1 = value,
+            None => swapped.push((position, value)),
+        }
+    };
+
+    let mut winners = Vec::with_capacity(winners_count as usize);
+    for i in 0..winners_count {
+        let j = i + stream.next_below((total_participants - i) as u64) as u32;
+        let picked = value_at(&swapped, j);
+        let current = value_at(&swapped, i);
+        set_at(&mut swapped, j, current);
+        set_at(&mut swapped, i, picked);
+        winners.push(picked);
+    }
+    winners
+}
+
+/// Ordered winners of a settled form, drawn or ranked depending on its winner selection
+pub fn draw_winners(
+    form: &Form,
+    random_value: &[u8; 32],
+    leaderboard: Option<&Leaderboard>,
+) -> Result<Vec<u32>> {
+    match form.winner_selection {
+        WinnerSelection::Lottery => Ok(select_winners(
+            random_value,
+            form.draw_pool_size(),
+            form.winner_count,
+        )),
+        WinnerSelection::SkillRanked => {
+            let leaderboard = leaderboard.ok_or(error!(ErrorCode::MissingLeaderboard))?;
+            Ok(rank_winners(random_value, &leaderboard.entries, form.winner_count))
+        }
+    }
+}
+
+/// Top `max_winners` entries by score, equal scores ordered by a hash of the
+/// random value so nobody can know the tie-break before settlement
+pub fn rank_winners(
+    random_value: &[u8; 32],
+    entries: &[LeaderboardEntry],
+    max_winners: u32,
+) -> Vec<u32> {
+    let tie_breaker = |entry: &LeaderboardEntry| {
+        hashv(&[
+            b"solana_form:tie_breaker".as_ref(),
+            random_value,
+            &entry.participant_index.to_le_bytes(),
+        ])
+        .to_bytes()
+    };
+
+    let mut ranked: Vec<(u32, [u8; 32], u32)> = entries
+        .iter()
+        .map(|entry| (entry.score, tie_breaker(entry), entry.participant_index))
+        .collect();
+    ranked.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    ranked
+        .into_iter()
+        .take(max_winners as usize)
+        .map(|(_, _, participant_index)| participant_index)
+        .collect()
+}
+
+/// Hash-chained stream of random words expanded from the settled random value
+struct RandomStream {
+    state: [u8; 32],
+    offset: usize,
+}
+
+impl RandomStream {
+    fn new(seed: &[u8; 32]) -> Self {
+        Self {
+            state: hashv(&[b"solana_form:winners".as_ref(), seed]).to_bytes(),
+            offset: 0,
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        if self.offset == 32 {
+            self.state = hashv(&[&self.state]).to_bytes();
+            self.offset = 0;
+        }
+        let mut word = [0u8; 8];
+        word.copy_from_slice(&self.state[self.offset..self.offset + 8]);
+        self.offset += 8;
+        u64::from_le_bytes(word)
+    }
+
+    /// Uniform value in `0..bound`, rejection sampling avoids modulo bias
+    fn next_below(&mut self, bound: u64) -> u64 {
+        let limit = u64::MAX - u64::MAX % bound;
+        loop {
+            let value = self.next_u64();
+            if value < limit {
+                return value % bound;
+            }
+        }
+    }
+}
+
+// ============ SEED SOURCES ============
+
+/// Seed for the slot-hash backend and the Switchboard emergency fallback
+/// Note: the authority chooses when to call, so this is less secure than
+/// Switchboard VRF or commit-reveal
+pub fn slot_hash_seed(slot_hash: &[u8; 32], form: &Pubkey, participant_count: u32) -> [u8; 32] {
+    hashv(&[
+        slot_hash,
+        form.as_ref(),
+        &participant_count.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Commit-reveal seed: hash(secret || participant accumulator || locked slot hash)
+pub fn commit_reveal_seed(
+    secret: &[u8; 32],
+    entropy_accumulator: &[u8; 32],
+    slot_hash: &[u8; 32],
+) -> [u8; 32] {
+    hashv(&[secret, entropy_accumulator, slot_hash]).to_bytes()
+}
+
+/// Hash of a commit-reveal secret, as stored in `Form.seed_commitment`
+pub fn seed_commitment(secret: &[u8; 32]) -> [u8; 32] {
+    hashv(&[secret]).to_bytes()
+}
+
+/// Mix a participant's key into the commit-reveal accumulator
+pub fn accumulate_entropy(entropy_accumulator: &[u8; 32], participant: &Pubkey) -> [u8; 32] {
+    hashv(&[entropy_accumulator, participant.as_ref()]).to_bytes()
+}
+
+// ============ SLOT HASHES ============
+
+const SLOT_HASH_ENTRY_LEN: usize = 8 + 32;
+
+/// Most recent slot hash in raw SlotHashes sysvar data
+pub fn latest_slot_hash(data: &[u8]) -> Option<[u8; 32]> {
+    slot_hash_entries(data).next().map(|(_, hash)| hash)
+}
+
+/// Hash of the first slot at or after `slot` in raw SlotHashes sysvar data
+/// Skipped slots have no entry, the next produced slot stands in for them. Only
+/// answers while an entry before `slot` is still present, so the first slot at or
+/// after it cannot have aged out and a later one cannot take its place.
+pub fn find_slot_hash(data: &[u8], slot: u64) -> Option<[u8; 32]> {
+    let mut first_at_or_after = None;
+    for (entry_slot, hash) in slot_hash_entries(data) {
+        if entry_slot < slot {
+            return first_at_or_after;
+        }
+        first_at_or_after = Some(hash);
+    }
+    None
+}
+
+/// Layout: u64 entry count, then (u64 slot, [u8; 32] hash) entries, newest first
+fn slot_hash_entries(data: &[u8]) -> impl Iterator<Item = (u64, [u8; 32])> + '_ {
+    let count = data
+        .get(0..8)
+        .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()) as usize)
+        .unwrap_or(0);
+
+    (0..count).map_while(move |i| {
+        let entry = data.get(8 + i * SLOT_HASH_ENTRY_LEN..8 + (i + 1) * SLOT_HASH_ENTRY_LEN)?;
+        let slot = u64::from_le_bytes(entry[0..8].try_into().unwrap());
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(&entry[8..]);
+        Some((slot, hash))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_valid_draw(winners: &[u32], total_participants: u32, max_winners: u32) {
+        assert_eq!(winners.len() as u32, total_participants.min(max_winners));
+        assert!(winners.iter().all(|index| *index < total_participants));
+        let mut sorted = winners.to_vec();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(sorted.len(), winners.len(), "winners must be distinct");
+    }
+
+    #[test]
+    fn select_winners_returns_min_of_participants_and_winners() {
+        let cases = [
+            (0, 0),
+            (0, 3),
+            (1, 1),
+            (2, 5),
+            (3, 3),
+            (10, 3),
+            (100, 10),
+            (1_000_000, 10),
+        ];
+        for (seed_byte, (total_participants, max_winners)) in cases.into_iter().enumerate() {
+            let seed = [seed_byte as u8; 32];
+            let winners = select_winners(&seed, total_participants, max_winners);
+            assert_valid_draw(&winners, total_participants, max_winners);
+        }
+    }
+
+    #[test]
+    fn select_winners_draws_everyone_when_fewer_participants_than_winners() {
+        let mut winners = select_winners(&[7u8; 32], 4, 10);
+        winners.sort_unstable();
+        assert_eq!(winners, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn select_winners_is_deterministic() {
+        for seed_byte in 0..16u8 {
+            let seed = [seed_byte; 32];
+            assert_eq!(select_winners(&seed, 50, 10), select_winners(&seed, 50, 10));
+        }
+        assert_ne!(
+            select_winners(&[1u8; 32], 1_000, 10),
+            select_winners(&[2u8; 32], 1_000, 10)
+        );
+    }
+
+    fn entry(participant_index: u32, score: u32) -> LeaderboardEntry {
+        LeaderboardEntry {
+            participant_index,
+            score,
+        }
+    }
+
+    #[test]
+    fn rank_winners_orders_by_score() {
+        let entries = [entry(0, 2), entry(1, 9), entry(2, 5), entry(3, 7)];
+        assert_eq!(rank_winners(&[1u8; 32], &entries, 3), vec![1, 3, 2]);
+        assert_eq!(rank_winners(&[1u8; 32], &entries, 10), vec![1, 3, 2, 0]);
+    }
+
+    #[test]
+    fn rank_winners_breaks_ties_with_the_random_value() {
+        let entries: Vec<LeaderboardEntry> = (0..8).map(|index| entry(index, 5)).collect();
+        let top = entry(8, 6);
+        let with_top: Vec<LeaderboardEntry> = entries
+            .iter()
+            .copied()
+            .chain(std::iter::once(top))
+            .collect();
+
+        // Deterministic for a given value, and the higher score always ranks first
+        let ranked = rank_winners(&[3u8; 32], &with_top, 4);
+        assert_eq!(ranked, rank_winners(&[3u8; 32], &with_top, 4));
+        assert_eq!(ranked[0], 8);
+
+        // Tie order follows the random value, not the insertion order
+        let orders: Vec<Vec<u32>> = (0..8u8)
+            .map(|seed_byte| rank_winners(&[seed_byte; 32], &entries, 8))
+            .collect();
+        assert!(orders.iter().any(|order| *order != orders[0]));
+        assert!(orders
+            .iter()
+            .any(|order| *order != (0..8).collect::<Vec<u32>>()));
+    }
+
+    /// Raw SlotHashes data for `slots` (newest first), each hashed to `[slot as u8; 32]`
+    fn slot_hashes(slots: &[u64]) -> Vec<u8> {
+        let mut data = (slots.len() as u64).to_le_bytes().to_vec();
+        for slot in slots {
+            data.extend_from_slice(&slot.to_le_bytes());
+            data.extend_from_slice(&[*slot as u8; 32]);
+        }
+        data
+    }
+
+    #[test]
+    fn find_slot_hash_returns_the_requested_slot() {
+        let data = slot_hashes(&[105, 104, 103, 102, 101]);
+        assert_eq!(find_slot_hash(&data, 103), Some([103u8; 32]));
+        assert_eq!(find_slot_hash(&data, 102), Some([102u8; 32]));
+        assert_eq!(latest_slot_hash(&data), Some([105u8; 32]));
+    }
+
+    #[test]
+    fn find_slot_hash_uses_the_next_slot_when_skipped() {
+        // 102 and 103 were skipped
+        let data = slot_hashes(&[106, 105, 104, 101, 100]);
+        assert_eq!(find_slot_hash(&data, 102), Some([104u8; 32]));
+        assert_eq!(find_slot_hash(&data, 103), Some([104u8; 32]));
+        assert_eq!(find_slot_hash(&data, 101), Some([101u8; 32]));
+    }
+
+    #[test]
+    fn find_slot_hash_waits_for_a_slot_at_or_after() {
+        let data = slot_hashes(&[104, 103, 102]);
+        assert_eq!(find_slot_hash(&data, 105), None);
+        assert_eq!(find_slot_hash(&slot_hashes(&[]), 105), None);
+    }
+
+    #[test]
+    fn find_slot_hash_needs_an_older_entry_in_the_window() {
+        // Slot 100 aged out, 101 may have been skipped or evicted
+        let data = slot_hashes(&[103, 102, 101]);
+        assert_eq!(find_slot_hash(&data, 101), None);
+        assert_eq!(find_slot_hash(&data, 100), None);
+        assert_eq!(find_slot_hash(&data, 102), Some([102u8; 32]));
+    }
+
+    #[test]
+    fn next_below_stays_in_range() {
+        let mut stream = RandomStream::new(&[3u8; 32]);
+        for bound in [1, 2, 3, 7, 10, 1_000, u64::MAX / 2 + 1, u64::MAX] {
+            for _ in 0..32 {
+                assert!(stream.next_below(bound) < bound);
+            }
+        }
+    }
+}
@@ -0,0 +1,738 @@
+use anchor_lang::prelude::*;
+
+use crate::envelope::validate_envelope;
+use crate::errors::ErrorCode;
+use crate::events::FormStatusChanged;
+
+pub const MAX_WINNERS: u32 = 10; // upper bound for Form.winner_count
+pub const BPS_DENOMINATOR: u64 = 10_000;
+pub const CLAIM_WINDOW_SECONDS: i64 = 2_592_000; // 30 days after settlement
+pub const ELIGIBILITY_WINDOW_SECONDS: i64 = 259_200; // 3 days after the answer key is revealed
+pub const MAX_LEADERBOARD_ENTRIES: u32 = 64; // top scores kept, including ties for the last prize
+pub const MAX_QUESTIONS: u32 = 32; // upper bound for FormSchema.questions
+pub const MAX_SHORT_TEXT_LEN: u16 = 280; // bytes, upper bound for a short text answer
+pub const MAX_RESPONSE_SIZE: u32 = 10_240; // bytes, largest account a single instruction can allocate
+pub const MAX_OPERATORS: u32 = 5; // upper bound for Form.operators
+
+// Operator permission bits
+pub const PERMISSION_REQUEST_RANDOMNESS: u8 = 1 << 0; // start and settle the draw
+pub const PERMISSION_EXTEND_DEADLINE: u8 = 1 << 1;
+pub const PERMISSION_EDIT_METADATA: u8 = 1 << 2; // schema, allowlist and participation gate
+pub const ALL_PERMISSIONS: u8 =
+    PERMISSION_REQUEST_RANDOMNESS | PERMISSION_EXTEND_DEADLINE | PERMISSION_EDIT_METADATA;
+
+// ============ ACCOUNTS ============
+
+#[account]
+#[derive(InitSpace)]
+pub struct Form {
+    pub authority: Pubkey,                     // 32
+    #[max_len(50)]
+    pub form_id: String,                       // 4 + 50
+    pub prize_pool: u64,                       // 8
+    pub collected_amount: u64,                 // 8
+    pub deadline: i64,                         // 8
+    pub max_participants: u32,                 // 4
+    pub participant_count: u32,                // 4
+    pub status: FormStatus,                    // 1
+    pub uses_fallback: bool,                   // 1 (marks if emergency fallback was used)
+    pub bump: u8,                              // 1
+    pub randomness_backend: RandomnessBackend, // 1
+    pub randomness_account: Pubkey,            // 32 (Switchboard only)
+    pub random_value: [u8; 32],                // 32
+    pub randomness_request_time: i64,          // 8 (for timeout check)
+    #[max_len(MAX_WINNERS)]
+    pub winners: Vec<u32>,                     // 4 + 4 * MAX_WINNERS (ordered winner indices)
+    pub seed_commitment: [u8; 32],             // 32 (commit-reveal: hash of the authority's secret)
+    pub entropy_accumulator: [u8; 32],         // 32 (commit-reveal: hash chain of participant keys)
+    pub reveal_slot: u64,                      // 8 (commit-reveal: first slot at or after it gives the hash mixed into the seed)
+    pub reveal_forfeited: bool,                // 1 (commit-reveal: authority never revealed)
+    pub prize_mint: Pubkey,                    // 32 (Pubkey::default() for native SOL)
+    pub prize_schedule: PrizeSchedule,         // 1 + 4 + 8 * MAX_WINNERS
+    pub winner_count: u32,                     // 4 (1..=MAX_WINNERS, winners drawn at settlement)
+    pub claim_deadline: i64,                   // 8 (set at settlement, unclaimed prizes sweepable after)
+    pub claimed_count: u32,                    // 4
+    pub claimed_amount: u64,                   // 8
+    pub allow_overfunding: bool,               // 1 (sponsors may raise the pool above prize_pool)
+    pub sponsored_amount: u64,                 // 8 (part of collected_amount owed back to sponsors on cancel)
+    pub entry_fee: u64,                        // 8 (charged in the prize currency on submit, 0 for free forms)
+    pub creator_fee_bps: u16,                  // 2 (creator's cut of each entry fee)
+    pub entry_fees_collected: u64,             // 8 (entry fees held, pool share + creator cut)
+    pub creator_fees: u64,                     // 8 (creator cut held outside collected_amount)
+    pub allowlist_root: [u8; 32],              // 32 (Merkle root of allowed wallets, zero for open forms)
+    pub email_verifier: Pubkey,                // 32 (signs email attestations, Pubkey::default() if unverified)
+    pub email_claim_count: u32,                // 4 (open EmailClaim accounts, must be 0 to close the form)
+    pub questions_hash: [u8; 32],              // 32 (commitment to the question set)
+    pub schema_version: u16,                   // 2 (version of the question set, answers must match it)
+    pub response_encryption_key: [u8; 32],     // 32 (creator's X25519 key responses are encrypted to, zero for plaintext)
+    pub answer_key_commitment: [u8; 32],       // 32 (quiz: hash of the answer key, zero for non-quiz forms)
+    #[max_len(MAX_QUESTIONS)]
+    pub answer_key: Vec<u8>,                   // 4 + MAX_QUESTIONS (quiz: correct choice per question, once revealed)
+    pub answer_key_revealed: bool,             // 1
+    pub eligibility_deadline: i64,             // 8 (quiz: participants prove correct answers until then)
+    pub eligible_count: u32,                   // 4 (quiz: winners are drawn among these)
+    pub winner_selection: WinnerSelection,     // 1
+    pub rent_reserve: u64,                     // 8 (lamports reimbursing relayers for participant rent)
+    pub participation_gate: ParticipationGate, // 1 + 40 (token or NFT holders only)
+    pub pending_authority: Pubkey,             // 32 (proposed new authority, Pubkey::default() if none)
+    #[max_len(MAX_OPERATORS)]
+    pub operators: Vec<Operator>,              // 4 + 33 * MAX_OPERATORS (delegated admins)
+    pub protocol_fee_bps: u16,                 // 2 (Config.protocol_fee_bps when the form was created)
+    pub response_count: u32,                   // 4 (open Response accounts, must be 0 to close the form)
+}
+
+impl Form {
+    /// Prize is held in the token vault instead of the form's lamports
+    pub fn is_token_pool(&self) -> bool {
+        self.prize_mint != Pubkey::default()
+    }
+
+    /// Only wallets in the Merkle allowlist can submit
+    pub fn is_allowlisted(&self) -> bool {
+        self.allowlist_root != [0u8; 32]
+    }
+
+    /// Submissions need an email attestation signed by `email_verifier`
+    pub fn requires_email_attestation(&self) -> bool {
+        self.email_verifier != Pubkey::default()
+    }
+
+    /// Response accounts hold envelopes encrypted to `response_encryption_key`
+    pub fn encrypts_responses(&self) -> bool {
+        self.response_encryption_key != [0u8; 32]
+    }
+
+    /// Only participants with correct answers can win
+    pub fn is_quiz(&self) -> bool {
+        self.answer_key_commitment != [0u8; 32]
+    }
+
+    /// Number of participants winners are drawn from
+    pub fn draw_pool_size(&self) -> u32 {
+        if self.is_quiz() {
+            self.eligible_count
+        } else {
+            self.participant_count
+        }
+    }
+
+    /// Index of `participant` in the draw, winners holds these, None if not eligible
+    /// Skill-ranked winners come from the leaderboard, which holds participant indices
+    pub fn draw_index(&self, participant: &Participant) -> Option<u32> {
+        if !self.is_quiz() || self.winner_selection == WinnerSelection::SkillRanked {
+            Some(participant.participant_index)
+        } else if participant.eligible {
+            Some(participant.eligible_index)
+        } else {
+            None
+        }
+    }
+
+    /// Quiz forms can only draw once every participant had the chance to prove eligibility
+    pub fn require_draw_ready(&self, now: i64) -> Result<()> {
+        if self.is_quiz() {
+            require!(self.answer_key_revealed, ErrorCode::AnswerKeyNotRevealed);
+            require!(
+                now > self.eligibility_deadline,
+                ErrorCode::EligibilityWindowOpen
+            );
+        }
+        require!(self.draw_pool_size() > 0, ErrorCode::NoParticipants);
+        Ok(())
+    }
+
+    /// `signer` is the authority, or an operator holding every bit of `permission`
+    pub fn is_authorized(&self, signer: &Pubkey, permission: u8) -> bool {
+        *signer == self.authority
+            || self.operators.iter().any(|operator| {
+                operator.key == *signer && operator.permissions & permission == permission
+            })
+    }
+
+    /// Every prize has been claimed, the rest of the pool is rounding dust
+    pub fn all_prizes_claimed(&self) -> bool {
+        let claimants = if self.reveal_forfeited {
+            self.participant_count
+        } else {
+            self.winners.len() as u32
+        };
+        self.claimed_count >= claimants
+    }
+
+    /// Fails with the error matching the current status if `to` is not reachable
+    pub fn check_transition(&self, to: FormStatus) -> Result<()> {
+        use FormStatus::*;
+
+        let allowed = matches!(
+            (self.status, to),
+            (Draft, Funded)
+                | (Funded, Open)
+                | (Funded | Open, Closed)
+                | (Closed, RandomnessPending | Settled)
+                | (RandomnessPending, Settled)
+                | (Draft | Funded | Open | Closed, Cancelled)
+                | (Draft | Funded | Closed | Settled | Cancelled, Finalized)
+        );
+        // The last withdrawal hands an Open form back to Funded
+        let emptied = self.status == Open && to == Funded && self.participant_count == 0;
+        if allowed || emptied {
+            return Ok(());
+        }
+
+        let error = match (self.status, to) {
+            (Draft, _) => ErrorCode::FormNotFunded,
+            (Funded | Open, Funded) => ErrorCode::PrizePoolFilled,
+            (Open, Finalized) => ErrorCode::CannotClose,
+            (Funded | Open, _) => ErrorCode::FormStillOpen,
+            (Closed, Open) => ErrorCode::DeadlinePassed,
+            (Closed, _) => ErrorCode::RandomnessNotRequested,
+            (RandomnessPending, _) => ErrorCode::RandomnessAlreadyRequested,
+            (Settled, _) => ErrorCode::AlreadyDistributed,
+            (Cancelled, _) => ErrorCode::FormCancelled,
+            (Finalized, _) => ErrorCode::FormFinalized,
+        };
+        Err(error.into())
+    }
+}
+
+/// Move a form to `to`, every status change goes through here
+pub fn transition(form: &mut Account<Form>, to: FormStatus) -> Result<()> {
+    form.check_transition(to)?;
+
+    let from = form.status;
+    let now = Clock::get()?.unix_timestamp;
+    form.status = to;
+
+    // Claim window opens with settlement, whichever backend settled the form
+    if to == FormStatus::Settled {
+        form.claim_deadline = now + CLAIM_WINDOW_SECONDS;
+    }
+
+    emit!(FormStatusChanged {
+        form: form.key(),
+        from,
+        to,
+        timestamp: now,
+    });
+    Ok(())
+}
+
+/// Close a participant-owned account, rent paid from the form's reserve goes back to it
+pub fn close_to_rent_payer<'info, T>(
+    account: &Account<'info, T>,
+    form: &mut Account<'info, Form>,
+    wallet: AccountInfo<'info>,
+    rent_sponsored: bool,
+) -> Result<()>
+where
+    T: AccountSerialize + AccountDeserialize + Owner + Clone,
+{
+    if rent_sponsored {
+        form.rent_reserve += account.to_account_info().lamports();
+        account.close(form.to_account_info())
+    } else {
+        account.close(wallet)
+    }
+}
+
+/// Stop accepting submissions, callers check the deadline first
+pub fn close_submissions(form: &mut Account<Form>) -> Result<()> {
+    match form.status {
+        FormStatus::Funded | FormStatus::Open => transition(form, FormStatus::Closed),
+        _ => Ok(()),
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Participant {
+    pub wallet: Pubkey,              // 32
+    pub form: Pubkey,                // 32
+    pub email_hash: [u8; 32],        // 32 (SHA256 hash)
+    pub timestamp: i64,              // 8
+    pub participant_index: u32,      // 4 (for deterministic winner selection)
+    pub is_winner: bool,             // 1
+    pub claimed: bool,               // 1
+    pub bump: u8,                    // 1
+    pub entry_fee_paid: u64,         // 8 (refunded if the form is cancelled)
+    pub allocation: u64,             // 8 (per-wallet allocation proven against the allowlist)
+    pub responses_hash: [u8; 32],    // 32 (commitment to the submitted answers)
+    pub schema_version: u16,         // 2 (Form.schema_version the answers were given for)
+    pub eligible: bool,              // 1 (quiz: answers proven correct)
+    pub eligible_index: u32,         // 4 (quiz: index in the draw)
+    pub score: u32,                  // 4 (skill-ranked: correct answers)
+    pub rent_sponsored: bool,        // 1 (rent paid by a relayer, reimbursed from the rent reserve)
+}
+
+/// Top scores of a skill-ranked form, sorted by score, ties are broken at settlement
+#[account]
+#[derive(InitSpace)]
+pub struct Leaderboard {
+    pub form: Pubkey,                // 32
+    #[max_len(MAX_LEADERBOARD_ENTRIES)]
+    pub entries: Vec<LeaderboardEntry>, // 4 + 8 * MAX_LEADERBOARD_ENTRIES
+    pub bump: u8,                    // 1
+}
+
+impl Leaderboard {
+    /// Keep every entry that can still win a prize: the top `winner_count`
+    /// scores and everything tied with the last of them
+    pub fn insert(&mut self, participant_index: u32, score: u32, winner_count: u32) -> Result<()> {
+        let position = self.entries.partition_point(|entry| entry.score >= score);
+        self.entries.insert(
+            position,
+            LeaderboardEntry {
+                participant_index,
+                score,
+            },
+        );
+
+        if let Some(cutoff) = self.entries.get(winner_count as usize - 1).map(|e| e.score) {
+            self.entries.retain(|entry| entry.score >= cutoff);
+        }
+        require!(
+            self.entries.len() <= MAX_LEADERBOARD_ENTRIES as usize,
+            ErrorCode::LeaderboardFull
+        );
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Sponsorship {
+    pub form: Pubkey,                // 32
+    pub sponsor: Pubkey,             // 32
+    pub amount: u64,                 // 8 (total received in the pool)
+    pub bump: u8,                    // 1
+}
+
+/// One per email hash and form, a second submission with the same email fails at init
+#[account]
+#[derive(InitSpace)]
+pub struct EmailClaim {
+    pub form: Pubkey,                // 32
+    pub wallet: Pubkey,              // 32 (gets the rent back on close unless sponsored)
+    pub bump: u8,                    // 1
+    pub rent_sponsored: bool,        // 1 (rent paid by a relayer, reimbursed from the rent reserve)
+}
+
+/// Question set of a form, answers in Response accounts are checked against it
+#[account]
+#[derive(InitSpace)]
+pub struct FormSchema {
+    pub form: Pubkey,                // 32
+    pub schema_version: u16,         // 2 (copied from Form.schema_version)
+    pub max_response_size: u32,      // 4 (creator's cap on a Response account, in bytes)
+    #[max_len(MAX_QUESTIONS)]
+    pub questions: Vec<Question>,    // 4 + 18 * MAX_QUESTIONS
+    pub bump: u8,                    // 1
+}
+
+impl FormSchema {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            !self.questions.is_empty()
+                && self.questions.len() <= MAX_QUESTIONS as usize
+                && self.max_response_size <= MAX_RESPONSE_SIZE,
+            ErrorCode::InvalidSchema
+        );
+        for question in &self.questions {
+            let valid = match question.kind {
+                QuestionKind::Choice { options } => options > 0,
+                QuestionKind::Integer { min, max } => min <= max,
+                QuestionKind::ShortText { max_len } => max_len <= MAX_SHORT_TEXT_LEN,
+            };
+            require!(valid, ErrorCode::InvalidSchema);
+        }
+        Ok(())
+    }
+
+    /// Plaintext answers for open forms, a well-formed envelope for encrypted ones
+    pub fn validate_payload(&self, payload: &ResponsePayload, encrypted: bool) -> Result<()> {
+        match (payload, encrypted) {
+            (ResponsePayload::Answers(answers), false) => self.validate_answers(answers),
+            (ResponsePayload::Encrypted(envelope), true) => validate_envelope(envelope),
+            _ => err!(ErrorCode::WrongResponseEncryption),
+        }
+    }
+
+    /// One answer per question, matching its kind and bounds
+    pub fn validate_answers(&self, answers: &[Answer]) -> Result<()> {
+        require!(
+            answers.len() == self.questions.len(),
+            ErrorCode::InvalidAnswers
+        );
+        for (question, answer) in self.questions.iter().zip(answers) {
+            let valid = match (question.kind, answer) {
+                (_, Answer::Skipped) => !question.required,
+                (QuestionKind::Choice { options }, Answer::Choice(choice)) => *choice < options,
+                (QuestionKind::Integer { min, max }, Answer::Integer(value)) => {
+                    (min..=max).contains(value)
+                }
+                (QuestionKind::ShortText { max_len }, Answer::ShortText(text)) => {
+                    text.len() <= max_len as usize
+                }
+                _ => false,
+            };
+            require!(valid, ErrorCode::InvalidAnswers);
+        }
+        Ok(())
+    }
+}
+
+/// Answers of one participant, sized to its payload and reallocated on update
+#[account]
+pub struct Response {
+    pub form: Pubkey,                // 32
+    pub wallet: Pubkey,              // 32
+    pub schema_version: u16,         // 2
+    pub payload: ResponsePayload,    // 1 + 4 + answers or envelope
+    pub bump: u8,                    // 1
+}
+
+impl Response {
+    /// Account size without the discriminator
+    pub fn space_for(payload: &ResponsePayload) -> usize {
+        32 + 32 + 2 + payload.size() + 1
+    }
+}
+
+/// Program-wide settings, a single account at [b"config"] created by the upgrade authority
+#[account]
+#[derive(InitSpace)]
+pub struct Config {
+    pub admin: Pubkey,               // 32 (updates the config and pauses the program)
+    pub protocol_fee_bps: u16,       // 2 (protocol cut of each prize claim, for forms created after)
+    pub treasury: Pubkey,            // 32 (withdraw_treasury pays out to this wallet)
+    pub max_prize_pool: u64,         // 8 (0 for no limit)
+    pub max_winners: u32,            // 4 (1..=MAX_WINNERS)
+    pub max_duration: i64,           // 8 (seconds from now to a form's deadline, 0 for no limit)
+    pub paused: bool,                // 1 (every form instruction but winner checks and claims fails while set)
+    pub bump: u8,                    // 1
+}
+
+impl Config {
+    /// Fee within 10000 bps, winner limit within MAX_WINNERS, no negative duration
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.protocol_fee_bps as u64 <= BPS_DENOMINATOR
+                && (1..=MAX_WINNERS).contains(&self.max_winners)
+                && self.max_duration >= 0,
+            ErrorCode::InvalidConfig
+        );
+        Ok(())
+    }
+
+    pub fn check_prize_pool(&self, prize_pool: u64) -> Result<()> {
+        require!(
+            self.max_prize_pool == 0 || prize_pool <= self.max_prize_pool,
+            ErrorCode::ExceedsProtocolLimits
+        );
+        Ok(())
+    }
+
+    pub fn check_deadline(&self, deadline: i64, now: i64) -> Result<()> {
+        require!(
+            self.max_duration == 0 || deadline.saturating_sub(now) <= self.max_duration,
+            ErrorCode::ExceedsProtocolLimits
+        );
+        Ok(())
+    }
+}
+
+/// Holds the protocol fees at [b"treasury"], SOL as lamports and tokens in token accounts it owns
+#[account]
+#[derive(InitSpace)]
+pub struct Treasury {
+    pub bump: u8,                    // 1
+}
+
+// ============ TYPES ============
+
+/// Form lifecycle, only changed through `transition`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum FormStatus {
+    /// Created, prize pool not fully deposited yet
+    Draft,
+    /// Prize pool deposited, no submissions yet
+    Funded,
+    /// Accepting submissions until the deadline
+    Open,
+    /// Deadline passed, winners not drawn yet
+    Closed,
+    /// Randomness requested, waiting for the oracle or the reveal
+    RandomnessPending,
+    /// Winners known, prizes claimable
+    Settled,
+    /// Aborted by the authority before randomness was requested
+    Cancelled,
+    /// Claims closed, unclaimed prizes swept or form closed, terminal
+    Finalized,
+}
+
+/// Where a form's winners come from, chosen once at initialize_form
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum RandomnessBackend {
+    /// Latest slot hash at distribution time, cheap but the authority picks the slot
+    SlotHash,
+    /// Switchboard on-demand randomness with an on-chain fallback
+    Switchboard,
+    /// Authority commits hash(secret) up front and reveals it after the deadline
+    CommitReveal,
+    /// Authority declares winners manually, no on-chain randomness
+    AdminDeclared,
+}
+
+/// How the collected pool is split between ranked winners
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum PrizeSplit {
+    /// collected_amount / winners, no tiers
+    Equal,
+    /// tiers[rank] is the share of the pool in basis points, summing to 10_000
+    BasisPoints,
+    /// tiers[rank] is an amount of the prize token, summing to prize_pool
+    FixedAmounts,
+}
+
+/// Prize per winner rank, rank 0 is the first winner drawn
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub struct PrizeSchedule {
+    pub split: PrizeSplit,
+    #[max_len(MAX_WINNERS)]
+    pub tiers: Vec<u64>,
+}
+
+impl PrizeSchedule {
+    pub fn validate(&self, prize_pool: u64, winner_count: u32) -> Result<()> {
+        let expected_total = match self.split {
+            PrizeSplit::Equal => {
+                require!(self.tiers.is_empty(), ErrorCode::InvalidPrizeSchedule);
+                return Ok(());
+            }
+            PrizeSplit::BasisPoints => BPS_DENOMINATOR,
+            PrizeSplit::FixedAmounts => prize_pool,
+        };
+
+        // One tier per winner rank
+        require!(
+            self.tiers.len() == winner_count as usize,
+            ErrorCode::InvalidPrizeSchedule
+        );
+        let total = self
+            .tiers
+            .iter()
+            .try_fold(0u64, |total, tier| total.checked_add(*tier))
+            .ok_or(error!(ErrorCode::MathOverflow))?;
+        require!(total == expected_total, ErrorCode::InvalidPrizeSchedule);
+        Ok(())
+    }
+
+    /// Prize for the winner at `rank` out of `winners_count` actual winners
+    /// Tiers without a winner (fewer participants than tiers) stay in the pool
+    pub fn prize_for_rank(
+        &self,
+        rank: usize,
+        winners_count: usize,
+        collected_amount: u64,
+        prize_pool: u64,
+    ) -> Result<u64> {
+        let (numerator, denominator) = match self.split {
+            PrizeSplit::Equal => return Ok(collected_amount / winners_count as u64),
+            PrizeSplit::BasisPoints => (self.tiers[rank], BPS_DENOMINATOR),
+            // Scaled by what was actually collected, equals the tier once fully funded
+            PrizeSplit::FixedAmounts => (self.tiers[rank], prize_pool),
+        };
+
+        let amount = (collected_amount as u128 * numerator as u128)
+            .checked_div(denominator as u128)
+            .unwrap_or(0);
+        u64::try_from(amount).map_err(|_| error!(ErrorCode::MathOverflow))
+    }
+}
+
+/// Split an entry fee into (pool share, creator cut)
+pub fn split_entry_fee(amount: u64, creator_fee_bps: u16) -> (u64, u64) {
+    let creator_cut = (amount as u128 * creator_fee_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+    (amount - creator_cut, creator_cut)
+}
+
+/// Split a prize into (amount paid to the winner, protocol fee)
+pub fn split_protocol_fee(amount: u64, protocol_fee_bps: u16) -> (u64, u64) {
+    let fee = (amount as u128 * protocol_fee_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+    (amount - fee, fee)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct LeaderboardEntry {
+    pub participant_index: u32,
+    pub score: u32,
+}
+
+/// Wallet allowed to run some admin actions on behalf of the authority
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct Operator {
+    pub key: Pubkey,
+    pub permissions: u8, // PERMISSION_* bits
+}
+
+/// Holding required to submit, checked in submit_form
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum ParticipationGate {
+    /// Anyone can submit
+    None,
+    /// At least `min_amount` of `mint` in a token account owned by the submitter
+    TokenBalance { mint: Pubkey, min_amount: u64 },
+    /// An NFT of the verified Metaplex `collection`
+    NftCollection { collection: Pubkey },
+}
+
+/// How winners are picked among the draw pool
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum WinnerSelection {
+    /// Uniform random draw
+    Lottery,
+    /// Highest quiz scores win, randomness only breaks ties
+    SkillRanked,
+}
+
+/// A question of a form schema
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct Question {
+    pub kind: QuestionKind,
+    /// Skipped answers are rejected for required questions
+    pub required: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum QuestionKind {
+    /// Index into `options` choices
+    Choice { options: u8 },
+    /// Integer in `min..=max`
+    Integer { min: i64, max: i64 },
+    /// UTF-8 text of at most `max_len` bytes
+    ShortText { max_len: u16 },
+}
+
+/// Answer to a question, variant must match the question kind
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum Answer {
+    Skipped,
+    Choice(u8),
+    Integer(i64),
+    ShortText(String),
+}
+
+/// Content of a Response, depending on whether the form encrypts responses
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum ResponsePayload {
+    /// Plaintext answers, one per schema question
+    Answers(Vec<Answer>),
+    /// Borsh-encoded answers sealed to Form.response_encryption_key, see envelope.rs
+    Encrypted(Vec<u8>),
+}
+
+impl ResponsePayload {
+    /// Serialized size in bytes
+    pub fn size(&self) -> usize {
+        1 + 4
+            + match self {
+                ResponsePayload::Answers(answers) => answers.iter().map(Answer::size).sum(),
+                ResponsePayload::Encrypted(envelope) => envelope.len(),
+            }
+    }
+}
+
+impl Answer {
+    /// Serialized size in bytes
+    pub fn size(&self) -> usize {
+        1 + match self {
+            Answer::Skipped => 0,
+            Answer::Choice(_) => 1,
+            Answer::Integer(_) => 8,
+            Answer::ShortText(text) => 4 + text.len(),
+        }
+    }
+}
+
+impl RandomnessBackend {
+    /// Whether this backend's instructions are compiled into the program
+    pub fn is_enabled(&self) -> bool {
+        match self {
+            RandomnessBackend::SlotHash => cfg!(feature = "slot-hash"),
+            RandomnessBackend::Switchboard => cfg!(feature = "switchboard"),
+            RandomnessBackend::CommitReveal => cfg!(feature = "commit-reveal"),
+            RandomnessBackend::AdminDeclared => cfg!(feature = "admin-declared"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_leaderboard() -> Leaderboard {
+        Leaderboard {
+            form: Pubkey::default(),
+            entries: Vec::new(),
+            bump: 0,
+        }
+    }
+
+    fn scores(leaderboard: &Leaderboard) -> Vec<u32> {
+        leaderboard
+            .entries
+            .iter()
+            .map(|entry| entry.score)
+            .collect()
+    }
+
+    #[test]
+    fn leaderboard_keeps_top_scores_sorted() {
+        let mut leaderboard = empty_leaderboard();
+        for (index, score) in [5, 1, 4, 2, 6].into_iter().enumerate() {
+            leaderboard.insert(index as u32, score, 2).unwrap();
+        }
+        assert_eq!(scores(&leaderboard), vec![6, 5]);
+        assert_eq!(leaderboard.entries[0].participant_index, 4);
+    }
+
+    #[test]
+    fn leaderboard_keeps_ties_at_the_cutoff() {
+        let mut leaderboard = empty_leaderboard();
+        for (index, score) in [5, 3, 3, 1].into_iter().enumerate() {
+            leaderboard.insert(index as u32, score, 2).unwrap();
+        }
+        assert_eq!(scores(&leaderboard), vec![5, 3, 3]);
+
+        // A higher score pushes the tied entries out
+        leaderboard.insert(4, 4, 2).unwrap();
+        assert_eq!(scores(&leaderboard), vec![5, 4]);
+    }
+
+    #[test]
+    fn leaderboard_keeps_everyone_below_winner_count() {
+        let mut leaderboard = empty_leaderboard();
+        for (index, score) in [1, 3, 2].into_iter().enumerate() {
+            leaderboard.insert(index as u32, score, 5).unwrap();
+        }
+        assert_eq!(scores(&leaderboard), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn leaderboard_fails_when_ties_overflow() {
+        let mut leaderboard = empty_leaderboard();
+        for index in 0..MAX_LEADERBOARD_ENTRIES {
+            leaderboard.insert(index, 7, 1).unwrap();
+        }
+        assert!(leaderboard.insert(MAX_LEADERBOARD_ENTRIES, 7, 1).is_err());
+
+        // A lower score is dropped instead of overflowing
+        let mut leaderboard = empty_leaderboard();
+        for index in 0..MAX_LEADERBOARD_ENTRIES {
+            leaderboard.insert(index, 7, 1).unwrap();
+        }
+        leaderboard.insert(MAX_LEADERBOARD_ENTRIES, 6, 1).unwrap();
+        assert_eq!(leaderboard.entries.len(), MAX_LEADERBOARD_ENTRIES as usize);
+    }
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    BaseStateWithExtensions, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_interface::{
+    self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::errors::ErrorCode;
+use crate::state::Form;
+
+// ============ TOKEN PRIZE VAULT ============
+// Token forms keep the prize in a token account at [b"vault", form], owned by the form PDA.
+
+/// Amount to send so that `net_amount` arrives after the mint's transfer fee
+/// Legacy SPL Token mints and Token-2022 mints without the extension have no fee
+pub fn gross_amount_for_net(mint: &InterfaceAccount<Mint>, net_amount: u64) -> Result<u64> {
+    let mint_info = mint.to_account_info();
+    let data = mint_info.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<MintState>::unpack(&data)?;
+
+    let fee = match mint_state.get_extension::<TransferFeeConfig>() {
+        Ok(fee_config) => fee_config
+            .calculate_inverse_epoch_fee(Clock::get()?.epoch, net_amount)
+            .ok_or(error!(ErrorCode::MathOverflow))?,
+        Err(_) => 0,
+    };
+
+    net_amount
+        .checked_add(fee)
+        .ok_or(error!(ErrorCode::MathOverflow))
+}
+
+/// Pay `amount` out of the vault, signed by the form PDA
+pub fn transfer_from_vault<'info>(
+    form: &Account<'info, Form>,
+    vault: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<()> {
+    let form_seeds = &[b"form", form.form_id.as_bytes(), &[form.bump]];
+    let signer_seeds = &[&form_seeds[..]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: vault.to_account_info(),
+                mint: mint.to_account_info(),
+                to: to.to_account_info(),
+                authority: form.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        mint.decimals,
+    )
+}
+
+/// Close the (empty) vault and return its rent, signed by the form PDA
+pub fn close_vault<'info>(
+    form: &Account<'info, Form>,
+    vault: &InterfaceAccount<'info, TokenAccount>,
+    destination: AccountInfo<'info>,
+    token_program: &Interface<'info, TokenInterface>,
+) -> Result<()> {
+    let form_seeds = &[b"form", form.form_id.as_bytes(), &[form.bump]];
+    let signer_seeds = &[&form_seeds[..]];
+
+    token_interface::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        CloseAccount {
+            account: vault.to_account_info(),
+            destination,
+            authority: form.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
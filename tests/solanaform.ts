@@ -1,7 +1,16 @@
 import * as anchor from '@coral-xyz/anchor';
-import { Program } from '@project-serum/anchor';
+import { Program } from '@coral-xyz/anchor';
 import { SolanaForm } from '../target/types/solana_form';
 import { assert } from 'chai';
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SYSVAR_SLOT_HASHES_PUBKEY,
+} from '@solana/web3.js';
+import { randomBytes } from 'crypto';
+
+const { BN } = anchor;
 
 describe('solana_form', () => {
   // Configure the client to use the local cluster.
@@ -15,12 +24,389 @@ describe('solana_form', () => {
   const prizePool = new anchor.BN(1 * anchor.web3.LAMPORTS_PER_SOL); // 1 SOL
   const deadline = new anchor.BN(Date.now() / 1000 + 60 * 60); // 1 hour from now
   const maxParticipants = 100;
+  const winnerCount = 3;
+  const questionsHash = Array.from(Buffer.alloc(32, 7)); // hash of the question set
 
   const [formPda] = anchor.web3.PublicKey.findProgramAddressSync(
     [Buffer.from('form'), Buffer.from(formId)],
     program.programId
   );
 
+  const [programData] = anchor.web3.PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    new anchor.web3.PublicKey('BPFLoaderUpgradeab1e11111111111111111111111')
+  );
+
+  const [configPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('config')],
+    program.programId
+  );
+  const [treasuryPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('treasury')],
+    program.programId
+  );
+  const protocolFeeBps = 100; // 1%, set by initialize_config below
+
+  // ============ HELPERS ============
+
+  const sleep = (ms: number) =>
+    new Promise((resolve) => setTimeout(resolve, ms));
+
+  const balance = (key: PublicKey) => provider.connection.getBalance(key);
+
+  // Prize minus the protocol fee, as paid out by claim_prize
+  const afterProtocolFee = (prize: number) =>
+    prize - Math.floor((prize * protocolFeeBps) / 10000);
+
+  const participantPda = (form: PublicKey, wallet: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('participant'), form.toBuffer(), wallet.toBuffer()],
+      program.programId
+    )[0];
+
+  const emailClaimPda = (form: PublicKey, emailHash: number[]) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('email'), form.toBuffer(), Buffer.from(emailHash)],
+      program.programId
+    )[0];
+
+  const sponsorshipPda = (form: PublicKey, sponsor: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('sponsorship'), form.toBuffer(), sponsor.toBuffer()],
+      program.programId
+    )[0];
+
+  // Funded wallet for participants, sponsors and relayers
+  const newWallet = async (sol = 2) => {
+    const wallet = Keypair.generate();
+    const signature = await provider.connection.requestAirdrop(
+      wallet.publicKey,
+      sol * LAMPORTS_PER_SOL
+    );
+    const latest = await provider.connection.getLatestBlockhash();
+    await provider.connection.confirmTransaction(
+      { signature, ...latest },
+      'confirmed'
+    );
+    return wallet;
+  };
+
+  // SOL prize, slot-hash lottery form owned by the provider wallet
+  const createForm = async (
+    options: {
+      prizePool?: anchor.BN;
+      deadlineIn?: number; // seconds from now
+      winnerCount?: number;
+      allowOverfunding?: boolean;
+      entryFee?: anchor.BN;
+      creatorFeeBps?: number;
+    } = {}
+  ) => {
+    const id = `form-${Date.now()}-${randomBytes(4).toString('hex')}`;
+    const [form] = PublicKey.findProgramAddressSync(
+      [Buffer.from('form'), Buffer.from(id)],
+      program.programId
+    );
+    const deadline = new BN(
+      Math.floor(Date.now() / 1000) + (options.deadlineIn ?? 10)
+    );
+
+    await program.methods
+      .initializeForm(
+        id,
+        options.prizePool ?? new BN(LAMPORTS_PER_SOL / 2),
+        deadline,
+        maxParticipants,
+        options.winnerCount ?? 1,
+        { slotHash: {} },
+        null,
+        { split: { equal: {} }, tiers: [] },
+        options.allowOverfunding ?? false,
+        options.entryFee ?? new BN(0),
+        options.creatorFeeBps ?? 0,
+        null,
+        null,
+        questionsHash,
+        1,
+        null,
+        { lottery: {} }
+      )
+      .accountsPartial({
+        form,
+        authority: authority.publicKey,
+        prizeMint: null,
+        vault: null,
+        tokenProgram: null,
+      })
+      .rpc();
+
+    return { form, deadline };
+  };
+
+  const deposit = (form: PublicKey) =>
+    program.methods
+      .depositPrize()
+      .accountsPartial({
+        form,
+        authority: authority.publicKey,
+        prizeMint: null,
+        authorityTokenAccount: null,
+        vault: null,
+        tokenProgram: null,
+      })
+      .rpc();
+
+  // Submit as `user`, a different `payer` relays it and pays the rent
+  const submit = async (
+    form: PublicKey,
+    user: Keypair,
+    payer: Keypair = user
+  ) => {
+    const emailHash = Array.from(randomBytes(32));
+    const participant = participantPda(form, user.publicKey);
+    const emailClaim = emailClaimPda(form, emailHash);
+
+    await program.methods
+      .submitForm(
+        emailHash,
+        [],
+        new BN(0),
+        new BN(0),
+        Array.from(randomBytes(32)),
+        1
+      )
+      .accountsPartial({
+        form,
+        participant,
+        emailClaim,
+        user: user.publicKey,
+        payer: payer.publicKey,
+        prizeMint: null,
+        userTokenAccount: null,
+        vault: null,
+        tokenProgram: null,
+        gateTokenAccount: null,
+        gateMetadata: null,
+        instructionsSysvar: null,
+      })
+      .signers(payer === user ? [user] : [user, payer])
+      .rpc();
+
+    return { participant, emailClaim, emailHash };
+  };
+
+  // Withdraw `user`, `last` is the participant holding the last index
+  const withdraw = (
+    form: PublicKey,
+    user: Keypair,
+    emailClaim: PublicKey,
+    last: PublicKey | null = null
+  ) =>
+    program.methods
+      .withdrawSubmission()
+      .accountsPartial({
+        form,
+        participant: participantPda(form, user.publicKey),
+        emailClaim,
+        response: null,
+        lastParticipant: last,
+        wallet: user.publicKey,
+        prizeMint: null,
+        vault: null,
+        walletTokenAccount: null,
+        tokenProgram: null,
+      })
+      .signers([user])
+      .rpc();
+
+  // Fee the provider wallet paid for the transaction `signature`
+  const feeOf = async (signature: string) => {
+    const latest = await provider.connection.getLatestBlockhash();
+    await provider.connection.confirmTransaction(
+      { signature, ...latest },
+      'confirmed'
+    );
+    const tx = await provider.connection.getTransaction(signature, {
+      commitment: 'confirmed',
+      maxSupportedTransactionVersion: 0,
+    });
+    return tx.meta.fee;
+  };
+
+  const cancel = (form: PublicKey) =>
+    program.methods
+      .cancelForm()
+      .accountsPartial({
+        form,
+        authority: authority.publicKey,
+        prizeMint: null,
+        vault: null,
+        authorityTokenAccount: null,
+        tokenProgram: null,
+      })
+      .rpc();
+
+  const closeParticipant = (form: PublicKey, wallet: PublicKey) =>
+    program.methods
+      .closeParticipant()
+      .accountsPartial({
+        form,
+        participant: participantPda(form, wallet),
+        response: null,
+        wallet,
+        prizeMint: null,
+        vault: null,
+        walletTokenAccount: null,
+        tokenProgram: null,
+      })
+      .rpc();
+
+  const closeEmailClaim = (
+    form: PublicKey,
+    wallet: PublicKey,
+    emailHash: number[]
+  ) =>
+    program.methods
+      .closeEmailClaim(emailHash)
+      .accountsPartial({
+        form,
+        emailClaim: emailClaimPda(form, emailHash),
+        wallet,
+      })
+      .rpc();
+
+  const closeForm = (form: PublicKey) =>
+    program.methods
+      .closeForm()
+      .accountsPartial({
+        form,
+        authority: authority.publicKey,
+        formSchema: null,
+        leaderboard: null,
+        prizeMint: null,
+        vault: null,
+        authorityTokenAccount: null,
+        tokenProgram: null,
+      })
+      .rpc();
+
+  const sponsorDeposit = (form: PublicKey, sponsor: Keypair, amount: number) =>
+    program.methods
+      .sponsorDeposit(new BN(amount))
+      .accountsPartial({
+        form,
+        sponsorship: sponsorshipPda(form, sponsor.publicKey),
+        sponsor: sponsor.publicKey,
+        prizeMint: null,
+        sponsorTokenAccount: null,
+        vault: null,
+        tokenProgram: null,
+      })
+      .signers([sponsor])
+      .rpc();
+
+  const refundSponsor = (form: PublicKey, sponsor: PublicKey) =>
+    program.methods
+      .refundSponsor()
+      .accountsPartial({
+        form,
+        sponsorship: sponsorshipPda(form, sponsor),
+        sponsor,
+        prizeMint: null,
+        vault: null,
+        sponsorTokenAccount: null,
+        tokenProgram: null,
+      })
+      .rpc();
+
+  const fundRentReserve = (form: PublicKey, amount: number) =>
+    program.methods
+      .fundRentReserve(new BN(amount))
+      .accountsPartial({ form, authority: authority.publicKey })
+      .rpc();
+
+  // Wait until the validator clock is past `deadline`
+  const waitUntil = async (deadline: anchor.BN) => {
+    for (;;) {
+      const time = await provider.connection.getBlockTime(
+        await provider.connection.getSlot()
+      );
+      if (time !== null && time > deadline.toNumber()) {
+        return;
+      }
+      await sleep(1000);
+    }
+  };
+
+  const distribute = (form: PublicKey) =>
+    program.methods
+      .distributePrizes()
+      .accountsPartial({
+        form,
+        authority: authority.publicKey,
+        slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+        leaderboard: null,
+      })
+      .rpc();
+
+  const claim = (form: PublicKey, winner: PublicKey) =>
+    program.methods
+      .claimPrize()
+      .accountsPartial({
+        form,
+        participant: participantPda(form, winner),
+        treasury: treasuryPda,
+        winner,
+        prizeMint: null,
+        vault: null,
+        winnerTokenAccount: null,
+        treasuryTokenAccount: null,
+        tokenProgram: null,
+      })
+      .rpc();
+
+  const setPaused = (paused: boolean) =>
+    program.methods
+      .setPaused(paused)
+      .accountsPartial({ config: configPda, admin: authority.publicKey })
+      .rpc();
+
+  // Await `call` and check it fails with the program error `code`
+  const expectError = async (call: Promise<unknown>, code: string) => {
+    try {
+      await call;
+    } catch (err) {
+      assert.include(String(err), code);
+      return;
+    }
+    assert.fail(`expected ${code}`);
+  };
+
+  // ============ TESTS ============
+
+  it('Initializes the program config', async () => {
+    await program.methods
+      .initializeConfig(
+        authority.publicKey,
+        protocolFeeBps,
+        authority.publicKey,
+        new anchor.BN(0), // no prize pool limit
+        10,
+        new anchor.BN(0) // no duration limit
+      )
+      .accountsPartial({
+        program: program.programId,
+        programData,
+        upgradeAuthority: authority.publicKey,
+      })
+      .rpc();
+
+    const configAccount = await program.account.config.fetch(configPda);
+    assert.ok(configAccount.admin.equals(authority.publicKey));
+    assert.equal(configAccount.protocolFeeBps, protocolFeeBps);
+    assert.equal(configAccount.paused, false);
+  });
+
   it('Initializes a form', async () => {
     // Call the initialize_form instruction
     await program.methods
@@ -28,12 +414,27 @@ describe('solana_form', () => {
         formId,
         prizePool,
         deadline,
-        maxParticipants
+        maxParticipants,
+        winnerCount,
+        { slotHash: {} },
+        null,
+        { split: { equal: {} }, tiers: [] },
+        false,
+        new anchor.BN(0),
+        0,
+        null,
+        null,
+        questionsHash,
+        1,
+        null,
+        { lottery: {} }
       )
-      .accounts({
+      .accountsPartial({
         form: formPda,
         authority: authority.publicKey,
-        systemProgram: anchor.web3.SystemProgram.programId,
+        prizeMint: null,
+        vault: null,
+        tokenProgram: null,
       })
       .rpc();
 
@@ -47,8 +448,320 @@ describe('solana_form', () => {
     assert.ok(formAccount.deadline.eq(deadline));
     assert.equal(formAccount.maxParticipants, maxParticipants);
     assert.equal(formAccount.participantCount, 0);
-    assert.isTrue(formAccount.isActive);
-    assert.isFalse(formAccount.isDistributed);
+    assert.equal(formAccount.winnerCount, winnerCount);
+    assert.deepEqual(formAccount.status, { draft: {} });
+    assert.deepEqual(formAccount.randomnessBackend, { slotHash: {} });
+    assert.ok(formAccount.prizeMint.equals(anchor.web3.PublicKey.default));
+    assert.deepEqual(formAccount.prizeSchedule.split, { equal: {} });
+    assert.equal(formAccount.allowOverfunding, false);
+    assert.ok(formAccount.entryFee.eqn(0));
+    assert.deepEqual(formAccount.allowlistRoot, new Array(32).fill(0));
+    assert.ok(formAccount.emailVerifier.equals(anchor.web3.PublicKey.default));
+    assert.deepEqual(formAccount.questionsHash, questionsHash);
+    assert.equal(formAccount.schemaVersion, 1);
+    assert.equal(formAccount.eligibleCount, 0);
+    assert.deepEqual(formAccount.winnerSelection, { lottery: {} });
+    assert.deepEqual(formAccount.participationGate, { none: {} });
+    assert.ok(formAccount.pendingAuthority.equals(anchor.web3.PublicKey.default));
+    assert.equal(formAccount.operators.length, 0);
+    assert.equal(formAccount.protocolFeeBps, protocolFeeBps); // snapshot of the config fee
+    assert.equal(formAccount.responseCount, 0);
+  });
+
+  it('Keeps winner checks and claims open while paused', async () => {
+    const prize = LAMPORTS_PER_SOL / 2;
+    const { form, deadline } = await createForm({ prizePool: new BN(prize) });
+    await deposit(form);
+    const winner = await newWallet();
+    const { participant } = await submit(form, winner);
+    await waitUntil(deadline);
+    await distribute(form);
+
+    await setPaused(true);
+    try {
+      await expectError(deposit(form), 'ProgramPaused');
+
+      // The only participant wins, no check_winner_status needed to claim
+      const before = await balance(winner.publicKey);
+      await claim(form, winner.publicKey);
+      assert.equal(
+        (await balance(winner.publicKey)) - before,
+        afterProtocolFee(prize)
+      );
+
+      await program.methods
+        .checkWinnerStatus()
+        .accountsPartial({ form, participant })
+        .rpc();
+    } finally {
+      await setPaused(false);
+    }
+
+    const account = await program.account.participant.fetch(participant);
+    assert.isTrue(account.isWinner);
+    assert.isTrue(account.claimed);
+  });
+
+  it('Moves the last participant into a withdrawn index', async () => {
+    const prize = LAMPORTS_PER_SOL / 2;
+    const entryFee = LAMPORTS_PER_SOL / 10;
+    const { form } = await createForm({
+      prizePool: new BN(prize),
+      deadlineIn: 60,
+      entryFee: new BN(entryFee),
+    });
+    await deposit(form);
+    const users = [await newWallet(), await newWallet(), await newWallet()];
+    const [first, second, third] = [
+      await submit(form, users[0]),
+      await submit(form, users[1]),
+      await submit(form, users[2]),
+    ];
+
+    // Index 0 is freed, the holder of the last index has to move into it
+    await expectError(
+      withdraw(form, users[0], first.emailClaim),
+      'InvalidLastParticipant'
+    );
+    const rent =
+      (await balance(first.participant)) + (await balance(first.emailClaim));
+    const before = await balance(users[0].publicKey);
+    await withdraw(form, users[0], first.emailClaim, third.participant);
+    assert.equal((await balance(users[0].publicKey)) - before, entryFee + rent);
+    assert.isNull(await provider.connection.getAccountInfo(first.participant));
+
+    const moved = await program.account.participant.fetch(third.participant);
+    assert.equal(moved.participantIndex, 0);
+    let account = await program.account.form.fetch(form);
+    assert.equal(account.participantCount, 2);
+    assert.equal(account.collectedAmount.toNumber(), prize + 2 * entryFee);
+
+    // The second now holds the last index and leaves without a swap
+    await withdraw(form, users[1], second.emailClaim);
+    await withdraw(form, users[2], third.emailClaim);
+
+    account = await program.account.form.fetch(form);
+    assert.equal(account.participantCount, 0);
+    assert.equal(account.emailClaimCount, 0);
+    assert.equal(account.collectedAmount.toNumber(), prize);
+    assert.deepEqual(account.status, { funded: {} });
+
+    // The next submission opens it again
+    await submit(form, await newWallet());
+    account = await program.account.form.fetch(form);
+    assert.deepEqual(account.status, { open: {} });
+  });
+
+  it('Refunds the pool on cancel and rent as accounts are closed', async () => {
+    const prize = LAMPORTS_PER_SOL / 2;
+    const { form } = await createForm({
+      prizePool: new BN(prize),
+      deadlineIn: 60,
+    });
+    await deposit(form);
+    const user = await newWallet();
+    const { participant, emailClaim, emailHash } = await submit(form, user);
+
+    // Only the deposit goes back, the form keeps its rent until close_form
+    const formRent = (await balance(form)) - prize;
+    let before = await balance(authority.publicKey);
+    let fee = await feeOf(await cancel(form));
+    assert.equal((await balance(authority.publicKey)) - before, prize - fee);
+    assert.equal(await balance(form), formRent);
+
+    // Participant and email claim rent goes back to the user
+    const rent = (await balance(participant)) + (await balance(emailClaim));
+    const userBefore = await balance(user.publicKey);
+    await closeParticipant(form, user.publicKey);
+    await expectError(closeForm(form), 'OpenEmailClaims');
+    await closeEmailClaim(form, user.publicKey, emailHash);
+    assert.equal((await balance(user.publicKey)) - userBefore, rent);
+
+    before = await balance(authority.publicKey);
+    fee = await feeOf(await closeForm(form));
+    assert.equal((await balance(authority.publicKey)) - before, formRent - fee);
+    assert.isNull(await provider.connection.getAccountInfo(form));
+  });
+
+  it('Refunds entry fees in full after a cancel', async () => {
+    const prize = LAMPORTS_PER_SOL / 2;
+    const entryFee = LAMPORTS_PER_SOL / 10;
+    const creatorFeeBps = 1000; // 10%
+    const creatorCut = (entryFee * creatorFeeBps) / 10000;
+    const { form } = await createForm({
+      prizePool: new BN(prize),
+      deadlineIn: 60,
+      entryFee: new BN(entryFee),
+      creatorFeeBps,
+    });
+    await deposit(form);
+    const users = [await newWallet(), await newWallet()];
+    for (const user of users) {
+      await submit(form, user);
+    }
+
+    let account = await program.account.form.fetch(form);
+    assert.equal(
+      account.collectedAmount.toNumber(),
+      prize + 2 * (entryFee - creatorCut)
+    );
+    assert.equal(account.creatorFees.toNumber(), 2 * creatorCut);
+
+    // collected - sponsored - (entry fees - creator cut) is just the deposit
+    const formBefore = await balance(form);
+    const before = await balance(authority.publicKey);
+    const fee = await feeOf(await cancel(form));
+    assert.equal((await balance(authority.publicKey)) - before, prize - fee);
+    assert.equal(formBefore - (await balance(form)), prize);
+
+    account = await program.account.form.fetch(form);
+    assert.equal(account.collectedAmount.toNumber(), 2 * entryFee);
+    assert.ok(account.creatorFees.eqn(0));
+
+    // The creator cut is given up, each participant gets the whole fee back
+    for (const user of users) {
+      const rent = await balance(participantPda(form, user.publicKey));
+      const userBefore = await balance(user.publicKey);
+      await closeParticipant(form, user.publicKey);
+      assert.equal(
+        (await balance(user.publicKey)) - userBefore,
+        entryFee + rent
+      );
+    }
+
+    account = await program.account.form.fetch(form);
+    assert.ok(account.collectedAmount.eqn(0));
+    assert.ok(account.entryFeesCollected.eqn(0));
+    assert.equal(account.participantCount, 0);
+  });
+
+  it('Returns sponsor deposits and rent after a cancel', async () => {
+    const prize = LAMPORTS_PER_SOL / 2;
+    const sponsored = LAMPORTS_PER_SOL / 4;
+    const { form } = await createForm({
+      prizePool: new BN(prize),
+      deadlineIn: 60,
+      allowOverfunding: true,
+    });
+    await deposit(form);
+    const sponsor = await newWallet();
+    const start = await balance(sponsor.publicKey);
+    await sponsorDeposit(form, sponsor, sponsored);
+    const rent = await balance(sponsorshipPda(form, sponsor.publicKey));
+    assert.equal(start - (await balance(sponsor.publicKey)), sponsored + rent);
+
+    let account = await program.account.form.fetch(form);
+    assert.equal(account.collectedAmount.toNumber(), prize + sponsored);
+    assert.equal(account.sponsoredAmount.toNumber(), sponsored);
+
+    // The authority only gets its own deposit back
+    const before = await balance(authority.publicKey);
+    const fee = await feeOf(await cancel(form));
+    assert.equal((await balance(authority.publicKey)) - before, prize - fee);
+    await expectError(closeForm(form), 'UnrefundedSponsors');
+
+    // Anyone can refund the sponsor, the sponsorship rent goes back too
+    await refundSponsor(form, sponsor.publicKey);
+    assert.equal(await balance(sponsor.publicKey), start);
+
+    account = await program.account.form.fetch(form);
+    assert.ok(account.collectedAmount.eqn(0));
+    assert.ok(account.sponsoredAmount.eqn(0));
+    await closeForm(form);
+    assert.isNull(await provider.connection.getAccountInfo(form));
+  });
+
+  it('Reimburses relayers from the rent reserve and refills it', async () => {
+    const { form } = await createForm({ deadlineIn: 60 });
+    await deposit(form);
+    const relayer = await newWallet();
+    await expectError(
+      submit(form, await newWallet(), relayer),
+      'RentReserveExhausted'
+    );
+
+    const reserve = LAMPORTS_PER_SOL / 10;
+    await fundRentReserve(form, reserve);
+
+    // The relayer fronts the rent and gets it back in the same transaction
+    const [alice, bob] = [await newWallet(), await newWallet()];
+    const relayerStart = await balance(relayer.publicKey);
+    const aliceStart = await balance(alice.publicKey);
+    const first = await submit(form, alice, relayer);
+    const second = await submit(form, bob, relayer);
+    assert.equal(await balance(relayer.publicKey), relayerStart);
+    assert.equal(await balance(alice.publicKey), aliceStart);
+
+    const rent =
+      (await balance(first.participant)) + (await balance(first.emailClaim));
+    let account = await program.account.form.fetch(form);
+    assert.equal(account.rentReserve.toNumber(), reserve - 2 * rent);
+
+    // Withdrawing puts the rent back in the reserve instead of the wallet
+    await withdraw(form, alice, first.emailClaim, second.participant);
+    assert.equal(await balance(alice.publicKey), aliceStart);
+    account = await program.account.form.fetch(form);
+    assert.equal(account.rentReserve.toNumber(), reserve - rent);
+
+    // So does closing the accounts of a cancelled form
+    await cancel(form);
+    await closeParticipant(form, bob.publicKey);
+    await closeEmailClaim(form, bob.publicKey, second.emailHash);
+    account = await program.account.form.fetch(form);
+    assert.equal(account.rentReserve.toNumber(), reserve);
+
+    // close_form hands the reserve to the authority along with the form's rent
+    const formBalance = await balance(form);
+    const before = await balance(authority.publicKey);
+    const fee = await feeOf(await closeForm(form));
+    assert.equal(
+      (await balance(authority.publicKey)) - before,
+      formBalance - fee
+    );
+  });
+
+  it('Pays the protocol fee of a claim into the treasury', async () => {
+    const prize = LAMPORTS_PER_SOL / 2 + 99; // the fee rounds down
+    const protocolFee = prize - afterProtocolFee(prize);
+    const { form, deadline } = await createForm({ prizePool: new BN(prize) });
+    await deposit(form);
+    const winner = await newWallet();
+    await submit(form, winner);
+    await waitUntil(deadline);
+    await distribute(form);
+
+    const treasuryBefore = await balance(treasuryPda);
+    const winnerBefore = await balance(winner.publicKey);
+    await claim(form, winner.publicKey);
+    assert.equal(
+      (await balance(winner.publicKey)) - winnerBefore,
+      afterProtocolFee(prize)
+    );
+    assert.equal((await balance(treasuryPda)) - treasuryBefore, protocolFee);
+    const account = await program.account.form.fetch(form);
+    assert.equal(account.claimedAmount.toNumber(), prize);
+
+    // The admin pays it out to the treasury wallet set in the config
+    const before = await balance(authority.publicKey);
+    const signature = await program.methods
+      .withdrawTreasury(new BN(protocolFee))
+      .accountsPartial({
+        config: configPda,
+        treasury: treasuryPda,
+        admin: authority.publicKey,
+        recipient: authority.publicKey,
+        mint: null,
+        treasuryTokenAccount: null,
+        recipientTokenAccount: null,
+        tokenProgram: null,
+      })
+      .rpc();
+    const fee = await feeOf(signature);
+    assert.equal(
+      (await balance(authority.publicKey)) - before,
+      protocolFee - fee
+    );
+    assert.equal(await balance(treasuryPda), treasuryBefore);
   });
 });
 
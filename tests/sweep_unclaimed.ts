@@ -0,0 +1,302 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Program } from '@coral-xyz/anchor';
+import { BankrunProvider, startAnchor } from 'anchor-bankrun';
+import { Clock, ProgramTestContext } from 'solana-bankrun';
+import { SolanaForm } from '../target/types/solana_form';
+import { assert } from 'chai';
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SYSVAR_SLOT_HASHES_PUBKEY,
+  SystemProgram,
+} from '@solana/web3.js';
+import { randomBytes } from 'crypto';
+
+const { BN } = anchor;
+
+// The claim window is 30 days, so these run on bankrun where the clock can jump
+describe('sweep_unclaimed', () => {
+  let context: ProgramTestContext;
+  let program: Program<SolanaForm>;
+
+  const creator = Keypair.generate();
+  const users = [Keypair.generate(), Keypair.generate()];
+  const prize = LAMPORTS_PER_SOL;
+
+  // ============ HELPERS ============
+
+  const pda = (...seeds: Buffer[]) =>
+    PublicKey.findProgramAddressSync(seeds, program.programId);
+
+  const balance = async (key: PublicKey) =>
+    Number(await context.banksClient.getBalance(key));
+
+  const now = async () =>
+    Number((await context.banksClient.getClock()).unixTimestamp);
+
+  // Advance a slot for a fresh blockhash and slot hash, then set the time
+  const warpTo = async (unixTimestamp: number) => {
+    context.warpToSlot((await context.banksClient.getSlot()) + BigInt(1));
+    const clock = await context.banksClient.getClock();
+    context.setClock(
+      new Clock(
+        clock.slot,
+        clock.epochStartTimestamp,
+        clock.epoch,
+        clock.leaderScheduleEpoch,
+        BigInt(unixTimestamp)
+      )
+    );
+  };
+
+  const fund = (wallet: PublicKey) =>
+    context.setAccount(wallet, {
+      lamports: 10 * LAMPORTS_PER_SOL,
+      data: Buffer.alloc(0),
+      owner: SystemProgram.programId,
+      executable: false,
+    });
+
+  // initialize_config needs an upgradeable deploy, write the account instead
+  const setProgramAccount = async (
+    address: PublicKey,
+    name: 'config' | 'treasury',
+    account: object
+  ) =>
+    context.setAccount(address, {
+      lamports: LAMPORTS_PER_SOL,
+      data: await program.coder.accounts.encode(name, account),
+      owner: program.programId,
+      executable: false,
+    });
+
+  // Await `call` and check it fails with the program error `code`
+  const expectError = async (call: Promise<unknown>, code: string) => {
+    try {
+      await call;
+    } catch (err) {
+      assert.include(String(err), code);
+      return;
+    }
+    assert.fail(`expected ${code}`);
+  };
+
+  const claim = (form: PublicKey, winner: PublicKey) =>
+    program.methods
+      .claimPrize()
+      .accountsPartial({
+        form,
+        participant: pda(
+          Buffer.from('participant'),
+          form.toBuffer(),
+          winner.toBuffer()
+        )[0],
+        treasury: pda(Buffer.from('treasury'))[0],
+        winner,
+        prizeMint: null,
+        vault: null,
+        winnerTokenAccount: null,
+        treasuryTokenAccount: null,
+        tokenProgram: null,
+      })
+      .rpc();
+
+  const sweep = (form: PublicKey) =>
+    program.methods
+      .sweepUnclaimed()
+      .accountsPartial({
+        form,
+        authority: creator.publicKey,
+        prizeMint: null,
+        vault: null,
+        authorityTokenAccount: null,
+        tokenProgram: null,
+      })
+      .signers([creator])
+      .rpc();
+
+  const closeForm = (form: PublicKey) =>
+    program.methods
+      .closeForm()
+      .accountsPartial({
+        form,
+        authority: creator.publicKey,
+        formSchema: null,
+        leaderboard: null,
+        prizeMint: null,
+        vault: null,
+        authorityTokenAccount: null,
+        tokenProgram: null,
+      })
+      .signers([creator])
+      .rpc();
+
+  before(async () => {
+    const idl = anchor.workspace.SolanaForm.rawIdl;
+    context = await startAnchor('.', [], []);
+    program = new Program<SolanaForm>(idl, new BankrunProvider(context));
+
+    for (const wallet of [creator, ...users]) {
+      await fund(wallet.publicKey);
+    }
+
+    const [config, configBump] = pda(Buffer.from('config'));
+    await setProgramAccount(config, 'config', {
+      admin: creator.publicKey,
+      protocolFeeBps: 0,
+      treasury: creator.publicKey,
+      maxPrizePool: new BN(0),
+      maxWinners: 10,
+      maxDuration: new BN(0),
+      paused: false,
+      bump: configBump,
+    });
+    const [treasury, treasuryBump] = pda(Buffer.from('treasury'));
+    await setProgramAccount(treasury, 'treasury', { bump: treasuryBump });
+  });
+
+  // ============ TESTS ============
+
+  it('Sweeps unclaimed prizes to the authority after the window', async () => {
+    const id = `sweep-${randomBytes(4).toString('hex')}`;
+    const [form] = pda(Buffer.from('form'), Buffer.from(id));
+    const deadline = (await now()) + 60;
+
+    // Two winners out of two participants, only the first one claims
+    await program.methods
+      .initializeForm(
+        id,
+        new BN(prize),
+        new BN(deadline),
+        10,
+        2,
+        { slotHash: {} },
+        null,
+        { split: { equal: {} }, tiers: [] },
+        false,
+        new BN(0),
+        0,
+        null,
+        null,
+        Array.from(Buffer.alloc(32, 7)),
+        1,
+        null,
+        { lottery: {} }
+      )
+      .accountsPartial({
+        form,
+        authority: creator.publicKey,
+        prizeMint: null,
+        vault: null,
+        tokenProgram: null,
+      })
+      .signers([creator])
+      .rpc();
+    await program.methods
+      .depositPrize()
+      .accountsPartial({
+        form,
+        authority: creator.publicKey,
+        prizeMint: null,
+        authorityTokenAccount: null,
+        vault: null,
+        tokenProgram: null,
+      })
+      .signers([creator])
+      .rpc();
+
+    const emailHashes = [];
+    for (const user of users) {
+      const emailHash = Array.from(randomBytes(32));
+      emailHashes.push(emailHash);
+      await program.methods
+        .submitForm(
+          emailHash,
+          [],
+          new BN(0),
+          new BN(0),
+          Array.from(randomBytes(32)),
+          1
+        )
+        .accountsPartial({
+          form,
+          participant: pda(
+            Buffer.from('participant'),
+            form.toBuffer(),
+            user.publicKey.toBuffer()
+          )[0],
+          emailClaim: pda(
+            Buffer.from('email'),
+            form.toBuffer(),
+            Buffer.from(emailHash)
+          )[0],
+          user: user.publicKey,
+          payer: user.publicKey,
+          prizeMint: null,
+          userTokenAccount: null,
+          vault: null,
+          tokenProgram: null,
+          gateTokenAccount: null,
+          gateMetadata: null,
+          instructionsSysvar: null,
+        })
+        .signers([user])
+        .rpc();
+    }
+
+    await warpTo(deadline + 1);
+    await program.methods
+      .distributePrizes()
+      .accountsPartial({
+        form,
+        authority: creator.publicKey,
+        slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+        leaderboard: null,
+      })
+      .signers([creator])
+      .rpc();
+    await claim(form, users[0].publicKey);
+
+    // The second prize is protected while the window is open
+    await expectError(closeForm(form), 'UnclaimedPrizes');
+    await expectError(sweep(form), 'ClaimWindowOpen');
+
+    let account = await program.account.form.fetch(form);
+    await warpTo(account.claimDeadline.toNumber() + 1);
+    await expectError(claim(form, users[1].publicKey), 'ClaimWindowClosed');
+
+    const unclaimed = prize - account.claimedAmount.toNumber();
+    assert.equal(unclaimed, prize / 2);
+    const formBefore = await balance(form);
+    const before = await balance(creator.publicKey);
+    await sweep(form);
+    assert.equal((await balance(creator.publicKey)) - before, unclaimed);
+    assert.equal(formBefore - (await balance(form)), unclaimed);
+
+    account = await program.account.form.fetch(form);
+    assert.deepEqual(account.status, { finalized: {} });
+    assert.ok(account.collectedAmount.eq(account.claimedAmount));
+
+    // Only rent is left, close_form takes it once the email claims are gone
+    for (const [index, user] of users.entries()) {
+      await program.methods
+        .closeEmailClaim(emailHashes[index])
+        .accountsPartial({
+          form,
+          emailClaim: pda(
+            Buffer.from('email'),
+            form.toBuffer(),
+            Buffer.from(emailHashes[index])
+          )[0],
+          wallet: user.publicKey,
+        })
+        .rpc();
+    }
+    const rent = await balance(form);
+    const beforeClose = await balance(creator.publicKey);
+    await closeForm(form);
+    assert.equal((await balance(creator.publicKey)) - beforeClose, rent);
+    assert.isNull(await context.banksClient.getAccount(form));
+  });
+});